@@ -1,5 +1,10 @@
 use std::env;
-use std::path::Path;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use graphql_client_codegen::{
+    CodegenMode, GraphQLClientCodegenOptions, generate_module_token_stream,
+};
 
 fn main() {
     let out_dir = env::var("OUT_DIR").unwrap();
@@ -8,6 +13,19 @@ fn main() {
     println!("cargo:rerun-if-changed=graphql/schema.graphql");
     println!("cargo:rerun-if-changed=graphql/queries.graphql");
 
-    // For now, we'll manually implement the queries
-    // In a production app, you'd use graphql_client_codegen here
+    let mut options = GraphQLClientCodegenOptions::new(CodegenMode::Cli);
+    options.set_module_visibility(syn::parse_quote!(pub));
+    options.set_response_derives("Debug, Clone".to_string());
+    options.set_variables_derives("Debug".to_string());
+
+    // Every operation in queries.graphql is validated against the schema here, so a
+    // query that no longer matches the API fails the build rather than the request.
+    let tokens = generate_module_token_stream(
+        PathBuf::from("graphql/queries.graphql"),
+        Path::new("graphql/schema.graphql"),
+        options,
+    )
+    .unwrap_or_else(|e| panic!("Failed to generate GraphQL operations: {e}"));
+
+    fs::write(&dest_path, tokens.to_string()).unwrap();
 }
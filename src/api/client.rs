@@ -1,62 +1,97 @@
-use anyhow::Result;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+
+use chrono::{DateTime, Utc};
+use graphql_client::{GraphQLQuery, QueryBody, Response};
+use reqwest::StatusCode;
 use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderValue};
-use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{Value, json};
 
-use super::types::{Issue, User};
+use super::error::{ApiError, Result};
+use super::queries::{
+    ArchiveNotification, CreateComment, CreateIssue, DeleteComment, FilteredCustomViewCreate,
+    FilteredCustomViewCreateVariables, FilteredCycles, FilteredCyclesVariables, FilteredIssues,
+    FilteredIssuesVariables, FilteredProjects, FilteredProjectsVariables, FilteredSearch,
+    FilteredSearchVariables, GetCustomViews, GetIssue, GetIssueComments, GetNotifications,
+    GetProject, GetTeams, GetViewer, MarkNotificationRead, MarkNotificationsRead, ResolveComment,
+    SnoozeNotification, UnresolveComment, UpdateComment, UpdateIssueAssignee, UpdateIssueCycle,
+    UpdateIssuePriority, UpdateIssueState, archive_notification, create_comment, create_issue,
+    delete_comment, get_custom_views, get_issue, get_issue_comments, get_notifications,
+    get_project, get_teams, get_viewer, mark_notification_read, mark_notifications_read,
+    resolve_comment, snooze_notification, unresolve_comment, update_comment, update_issue_assignee,
+    update_issue_cycle, update_issue_priority, update_issue_state,
+};
+use super::rate_limit::{self, MAX_DELAY, RateLimit};
+use super::types::{
+    Comment, CustomView, Cycle, Issue, IssueDetail, IssueQuery, NewIssue, Notification, Page,
+    Priority, Project, ProjectDetail, Team, User,
+};
 
 const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
+/// How many times a failed query is retried before giving up.
+const MAX_RETRIES: u32 = 3;
+/// Projects and cycles are shown whole, so their issues are fetched in large pages.
+const ALL_ISSUES_PAGE_SIZE: i32 = 250;
+/// The inbox only looks this far back, as an ISO 8601 duration.
+const NOTIFICATIONS_SINCE: &str = "-P4W";
+/// Caps the pages of 100 notifications fetched for the inbox.
+const MAX_NOTIFICATION_PAGES: usize = 5;
 
+#[derive(Clone)]
 pub struct LinearClient {
     client: reqwest::blocking::Client,
-    api_key: String,
+    /// Shared by every clone, so the UI sees the quota of the worker's requests.
+    rate_limit: Arc<Mutex<RateLimit>>,
 }
 
-#[derive(Debug, Serialize)]
-struct GraphQLRequest {
-    query: String,
-    variables: Option<serde_json::Value>,
+/// Walks the `issues` connection, or the search results, one page at a time,
+/// following `endCursor` until the API reports there is no next page.
+pub struct IssuePages {
+    client: LinearClient,
+    page_size: i32,
+    query: IssueQuery,
+    after: Option<String>,
+    done: bool,
 }
 
-#[derive(Debug, Deserialize)]
-struct GraphQLResponse<T> {
-    data: Option<T>,
-    errors: Option<Vec<GraphQLError>>,
-}
+impl IssuePages {
+    pub fn has_more(&self) -> bool {
+        !self.done
+    }
 
-#[derive(Debug, Deserialize)]
-struct GraphQLError {
-    message: String,
+    pub fn query(&self) -> &IssueQuery {
+        &self.query
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct IssuesResponse {
-    issues: Connection<Issue>,
-}
+impl Iterator for IssuePages {
+    type Item = Result<Vec<Issue>>;
 
-#[derive(Debug, Deserialize)]
-struct Connection<T> {
-    edges: Vec<Edge<T>>,
-    #[serde(rename = "pageInfo")]
-    page_info: PageInfo,
-}
-
-#[derive(Debug, Deserialize)]
-struct Edge<T> {
-    node: T,
-    cursor: Option<String>,
-}
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
 
-#[derive(Debug, Deserialize)]
-struct PageInfo {
-    #[serde(rename = "hasNextPage")]
-    has_next_page: bool,
-    #[serde(rename = "endCursor")]
-    end_cursor: Option<String>,
+        match self
+            .client
+            .get_issues_page(self.page_size, self.after.as_deref(), &self.query)
+        {
+            Ok(page) => {
+                self.done = !page.page_info.has_next_page || page.page_info.end_cursor.is_none();
+                self.after = page.page_info.end_cursor;
+                Some(Ok(page.nodes))
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
 }
 
 impl LinearClient {
-    pub fn new(api_key: String) -> Result<Self> {
+    pub fn new(api_key: String) -> anyhow::Result<Self> {
         let mut headers = HeaderMap::new();
         headers.insert(AUTHORIZATION, HeaderValue::from_str(&api_key)?);
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
@@ -65,10 +100,13 @@ impl LinearClient {
             .default_headers(headers)
             .build()?;
 
-        Ok(Self { client, api_key })
+        Ok(Self {
+            client,
+            rate_limit: Arc::default(),
+        })
     }
 
-    pub fn new_with_oauth(access_token: String) -> Result<Self> {
+    pub fn new_with_oauth(access_token: String) -> anyhow::Result<Self> {
         let mut headers = HeaderMap::new();
         headers.insert(
             AUTHORIZATION,
@@ -82,110 +120,552 @@ impl LinearClient {
 
         Ok(Self {
             client,
-            api_key: access_token,
+            rate_limit: Arc::default(),
         })
     }
 
-    pub fn get_issues(&self, limit: i32) -> Result<Vec<Issue>> {
-        let query = r#"
-            query GetIssues($first: Int!) {
-                issues(first: $first) {
-                    edges {
-                        node {
-                            id
-                            identifier
-                            title
-                            description
-                            priority
-                            createdAt
-                            updatedAt
-                            state {
-                                id
-                                name
-                                color
-                            }
-                            assignee {
-                                id
-                                name
-                                email
-                            }
-                        }
+    /// Latest quota reported by Linear; empty until the first response.
+    pub fn rate_limit(&self) -> RateLimit {
+        *self
+            .rate_limit
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Runs a read-only operation, retrying transient failures with backoff.
+    fn query<Q: GraphQLQuery>(&self, variables: Q::Variables) -> Result<Q::ResponseData> {
+        let request = Q::build_query(variables);
+        let mut attempt = 0;
+
+        loop {
+            match self.send::<Q>(&request) {
+                Err(error) if error.is_retryable() && attempt < MAX_RETRIES => {
+                    let delay = match error {
+                        ApiError::RateLimited {
+                            retry_after: Some(wait),
+                        } => wait,
+                        _ => rate_limit::backoff(attempt),
+                    };
+                    if delay > MAX_DELAY {
+                        return Err(error);
                     }
+
+                    thread::sleep(delay);
+                    attempt += 1;
                 }
+                result => return result,
             }
-        "#;
-
-        let variables = json!({
-            "first": limit
-        });
+        }
+    }
 
-        let request = GraphQLRequest {
-            query: query.to_string(),
-            variables: Some(variables),
-        };
+    /// Runs a mutation exactly once; retrying could apply it twice.
+    fn mutate<Q: GraphQLQuery>(&self, variables: Q::Variables) -> Result<Q::ResponseData> {
+        self.send::<Q>(&Q::build_query(variables))
+    }
 
-        let response: GraphQLResponse<IssuesResponse> = self
+    fn send<Q: GraphQLQuery>(&self, request: &QueryBody<Q::Variables>) -> Result<Q::ResponseData> {
+        let response = self
             .client
             .post(LINEAR_API_URL)
-            .json(&request)
-            .send()?
-            .json()?;
+            .json(request)
+            .send()
+            .map_err(ApiError::Transport)?;
+        let status = response.status();
+        let rate_limit = RateLimit::from_headers(response.headers());
+        if rate_limit.requests.is_some() || rate_limit.complexity.is_some() {
+            *self
+                .rate_limit
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = rate_limit;
+        }
+        let retry_after = rate_limit.retry_after(response.headers());
+        let body = response.text().map_err(ApiError::Transport)?;
+
+        // Linear reports most failures as GraphQL errors, even on a 4xx, so
+        // the body is more informative than the status whenever it parses.
+        let response: Response<Q::ResponseData> = match serde_json::from_str(&body) {
+            Ok(response) => response,
+            Err(e) => {
+                return Err(match status {
+                    StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                        ApiError::Unauthenticated(status.to_string())
+                    }
+                    StatusCode::TOO_MANY_REQUESTS => ApiError::RateLimited { retry_after },
+                    _ if !status.is_success() => ApiError::Http { status, body },
+                    _ => ApiError::Decode(e),
+                });
+            }
+        };
 
-        if let Some(errors) = response.errors {
-            let error_messages: Vec<String> = errors.iter().map(|e| e.message.clone()).collect();
-            anyhow::bail!("GraphQL errors: {}", error_messages.join(", "));
+        if let Some(errors) = response.errors.filter(|errors| !errors.is_empty()) {
+            return Err(match ApiError::from_graphql(errors) {
+                ApiError::RateLimited { .. } => ApiError::RateLimited { retry_after },
+                error => error,
+            });
         }
 
-        let issues = response
-            .data
-            .ok_or_else(|| anyhow::anyhow!("No data in response"))?
-            .issues
+        response.data.ok_or(ApiError::MissingData)
+    }
+
+    pub fn get_issues_page(
+        &self,
+        first: i32,
+        after: Option<&str>,
+        query: &IssueQuery,
+    ) -> Result<Page<Issue>> {
+        if let Some(term) = &query.search {
+            let variables = FilteredSearchVariables {
+                term: term.clone(),
+                first: first.into(),
+                after: after.map(str::to_string),
+                filter: query.filter.clone(),
+                include_archived: query.include_archived,
+            };
+            return Ok(self
+                .query::<FilteredSearch>(variables)?
+                .search_issues
+                .into());
+        }
+
+        let variables = FilteredIssuesVariables {
+            first: first.into(),
+            after: after.map(str::to_string),
+            filter: query.filter.clone(),
+            include_archived: query.include_archived,
+        };
+        Ok(self.query::<FilteredIssues>(variables)?.issues.into())
+    }
+
+    pub fn issue_pages(&self, page_size: i32, query: IssueQuery) -> IssuePages {
+        IssuePages {
+            client: self.clone(),
+            page_size,
+            query,
+            after: None,
+            done: false,
+        }
+    }
+
+    /// Fetches an issue with every one of its comments, a page at a time.
+    pub fn get_issue(&self, id: &str) -> Result<IssueDetail> {
+        let variables = get_issue::Variables { id: id.to_string() };
+        let issue = self.query::<GetIssue>(variables)?.issue;
+
+        let mut page: Page<Comment> = issue.comments.into();
+        let mut comments = Vec::new();
+        loop {
+            comments.append(&mut page.nodes);
+            let Some(after) = page
+                .page_info
+                .end_cursor
+                .filter(|_| page.page_info.has_next_page)
+            else {
+                break;
+            };
+            let variables = get_issue_comments::Variables {
+                id: issue.issue_fields.id.clone(),
+                after: Some(after),
+            };
+            page = self
+                .query::<GetIssueComments>(variables)?
+                .issue
+                .comments
+                .into();
+        }
+
+        Ok(IssueDetail::new(issue.issue_fields.into(), comments))
+    }
+
+    pub fn get_viewer(&self) -> Result<User> {
+        Ok(self
+            .query::<GetViewer>(get_viewer::Variables)?
+            .viewer
+            .into())
+    }
+
+    pub fn get_teams(&self) -> Result<Vec<Team>> {
+        let teams = self
+            .query::<GetTeams>(get_teams::Variables)?
+            .teams
             .edges
             .into_iter()
-            .map(|edge| edge.node)
+            .map(|edge| edge.node.into())
             .collect();
 
+        Ok(teams)
+    }
+
+    /// Lists every project, most recently updated first, a page at a time.
+    pub fn get_projects(&self, filter: Option<Value>) -> Result<Vec<Project>> {
+        let mut projects = Vec::new();
+        let mut after = None;
+        loop {
+            let variables = FilteredProjectsVariables {
+                after,
+                filter: filter.clone(),
+            };
+            let page = self.query::<FilteredProjects>(variables)?.projects;
+            projects.extend(page.nodes.into_iter().map(Project::from));
+
+            after = page.page_info.end_cursor;
+            if !page.page_info.has_next_page || after.is_none() {
+                break;
+            }
+        }
+
+        Ok(projects)
+    }
+
+    /// Fetches a project with its latest updates and every one of its issues.
+    pub fn get_project(&self, id: &str) -> Result<ProjectDetail> {
+        let variables = get_project::Variables { id: id.to_string() };
+        let mut detail: ProjectDetail = self.query::<GetProject>(variables)?.project.into();
+        detail.issues = self.all_issues(json!({ "project": { "id": { "eq": id } } }))?;
+
+        Ok(detail)
+    }
+
+    /// Lists every cycle across the viewer's teams, a page at a time.
+    pub fn get_cycles(&self, filter: Option<Value>) -> Result<Vec<Cycle>> {
+        let mut cycles = Vec::new();
+        let mut after = None;
+        loop {
+            let variables = FilteredCyclesVariables {
+                after,
+                filter: filter.clone(),
+            };
+            let page = self.query::<FilteredCycles>(variables)?.cycles;
+            cycles.extend(page.nodes.into_iter().map(Cycle::from));
+
+            after = page.page_info.end_cursor;
+            if !page.page_info.has_next_page || after.is_none() {
+                break;
+            }
+        }
+
+        Ok(cycles)
+    }
+
+    /// Fetches every issue in a cycle.
+    pub fn get_cycle_issues(&self, id: &str) -> Result<Vec<Issue>> {
+        self.all_issues(json!({ "cycle": { "id": { "eq": id } } }))
+    }
+
+    /// Follows every page of the issues matching `filter`.
+    fn all_issues(&self, filter: Value) -> Result<Vec<Issue>> {
+        let query = IssueQuery {
+            filter: Some(filter),
+            ..IssueQuery::default()
+        };
+        let mut issues = Vec::new();
+        for page in self.issue_pages(ALL_ISSUES_PAGE_SIZE, query) {
+            issues.extend(page?);
+        }
+
         Ok(issues)
     }
 
-    pub fn get_viewer(&self) -> Result<User> {
-        let query = r#"
-            query GetViewer {
-                viewer {
-                    id
-                    name
-                    email
-                }
+    /// Lists the issue views the viewer can see: the workspace's shared views
+    /// and their own personal ones. Views of projects and other models are skipped.
+    pub fn get_custom_views(&self) -> Result<Vec<CustomView>> {
+        let mut views = Vec::new();
+        let mut after = None;
+        loop {
+            let page = self
+                .query::<GetCustomViews>(get_custom_views::Variables { after })?
+                .custom_views;
+            views.extend(
+                page.nodes
+                    .into_iter()
+                    .filter(|view| view.model_name == "Issue")
+                    .map(CustomView::from),
+            );
+
+            after = page.page_info.end_cursor;
+            if !page.page_info.has_next_page || after.is_none() {
+                break;
             }
-        "#;
+        }
+
+        Ok(views)
+    }
 
-        let request = GraphQLRequest {
-            query: query.to_string(),
-            variables: None,
+    pub fn create_custom_view(
+        &self,
+        name: &str,
+        filter: Value,
+        shared: bool,
+    ) -> Result<CustomView> {
+        let variables = FilteredCustomViewCreateVariables {
+            name: name.to_string(),
+            filter_data: filter,
+            shared,
         };
 
-        #[derive(Deserialize)]
-        struct ViewerResponse {
-            viewer: User,
+        let payload = self
+            .mutate::<FilteredCustomViewCreate>(variables)?
+            .custom_view_create;
+
+        if !payload.success {
+            return Err(ApiError::Rejected("View was not created"));
         }
 
-        let response: GraphQLResponse<ViewerResponse> = self
-            .client
-            .post(LINEAR_API_URL)
-            .json(&request)
-            .send()?
-            .json()?;
+        Ok(payload.custom_view.into())
+    }
 
-        if let Some(errors) = response.errors {
-            let error_messages: Vec<String> = errors.iter().map(|e| e.message.clone()).collect();
-            anyhow::bail!("GraphQL errors: {}", error_messages.join(", "));
+    pub fn create_issue(&self, issue: NewIssue) -> Result<Issue> {
+        let variables = create_issue::Variables {
+            team_id: issue.team_id,
+            title: issue.title,
+            description: issue.description,
+            priority: issue.priority.map(|p| u8::from(p).into()),
+            assignee_id: issue.assignee_id,
+            state_id: issue.state_id,
+        };
+
+        let payload = self.mutate::<CreateIssue>(variables)?.issue_create;
+
+        match payload.issue {
+            Some(issue) if payload.success => Ok(issue.into()),
+            _ => Err(ApiError::Rejected("Issue was not created")),
+        }
+    }
+
+    pub fn update_issue_state(&self, id: &str, state_id: &str) -> Result<Issue> {
+        let variables = update_issue_state::Variables {
+            id: id.to_string(),
+            state_id: state_id.to_string(),
+        };
+
+        let payload = self.mutate::<UpdateIssueState>(variables)?.issue_update;
+
+        match payload.issue {
+            Some(issue) if payload.success => Ok(issue.into()),
+            _ => Err(ApiError::Rejected("Issue was not updated")),
+        }
+    }
+
+    /// Moves an issue into a cycle, or out of its cycle when `cycle_id` is `None`.
+    pub fn update_issue_cycle(&self, id: &str, cycle_id: Option<&str>) -> Result<Issue> {
+        let variables = update_issue_cycle::Variables {
+            id: id.to_string(),
+            cycle_id: cycle_id.map(str::to_string),
+        };
+
+        let payload = self.mutate::<UpdateIssueCycle>(variables)?.issue_update;
+
+        match payload.issue {
+            Some(issue) if payload.success => Ok(issue.into()),
+            _ => Err(ApiError::Rejected("Issue was not updated")),
+        }
+    }
+
+    /// Assigns an issue to a user, or unassigns it when `assignee_id` is `None`.
+    pub fn update_issue_assignee(&self, id: &str, assignee_id: Option<&str>) -> Result<Issue> {
+        let variables = update_issue_assignee::Variables {
+            id: id.to_string(),
+            assignee_id: assignee_id.map(str::to_string),
+        };
+
+        let payload = self.mutate::<UpdateIssueAssignee>(variables)?.issue_update;
+
+        match payload.issue {
+            Some(issue) if payload.success => Ok(issue.into()),
+            _ => Err(ApiError::Rejected("Issue was not updated")),
         }
+    }
+
+    pub fn update_issue_priority(&self, id: &str, priority: Priority) -> Result<Issue> {
+        let variables = update_issue_priority::Variables {
+            id: id.to_string(),
+            priority: u8::from(priority).into(),
+        };
+
+        let payload = self.mutate::<UpdateIssuePriority>(variables)?.issue_update;
+
+        match payload.issue {
+            Some(issue) if payload.success => Ok(issue.into()),
+            _ => Err(ApiError::Rejected("Issue was not updated")),
+        }
+    }
+
+    pub fn create_comment(&self, issue_id: &str, body: &str) -> Result<Comment> {
+        let variables = create_comment::Variables {
+            issue_id: issue_id.to_string(),
+            body: body.to_string(),
+        };
+
+        let payload = self.mutate::<CreateComment>(variables)?.comment_create;
+        if !payload.success {
+            return Err(ApiError::Rejected("Comment was not created"));
+        }
+
+        Ok(payload.comment.into())
+    }
+
+    pub fn update_comment(&self, id: &str, body: &str) -> Result<Comment> {
+        let variables = update_comment::Variables {
+            id: id.to_string(),
+            body: body.to_string(),
+        };
+
+        let payload = self.mutate::<UpdateComment>(variables)?.comment_update;
+        if !payload.success {
+            return Err(ApiError::Rejected("Comment was not updated"));
+        }
+
+        Ok(payload.comment.into())
+    }
+
+    /// The viewer's inbox: unread notifications from the last
+    /// `NOTIFICATIONS_SINCE` that aren't snoozed, newest first. Linear can't
+    /// filter on `readAt`, so read ones are dropped here, and at most
+    /// `MAX_NOTIFICATION_PAGES` pages are fetched.
+    pub fn get_notifications(&self) -> Result<Vec<Notification>> {
+        let now = Utc::now();
+        let mut notifications: Vec<Notification> = Vec::new();
+        let mut after = None;
+        for _ in 0..MAX_NOTIFICATION_PAGES {
+            let variables = get_notifications::Variables {
+                after,
+                since: NOTIFICATIONS_SINCE.to_string(),
+            };
+            let page = self.query::<GetNotifications>(variables)?.notifications;
+            notifications.extend(
+                page.nodes
+                    .into_iter()
+                    .filter(|n| {
+                        n.read_at.is_none() && n.snoozed_until_at.is_none_or(|until| until <= now)
+                    })
+                    .map(Notification::from),
+            );
 
-        let viewer = response
-            .data
-            .ok_or_else(|| anyhow::anyhow!("No data in response"))?
-            .viewer;
+            after = page.page_info.end_cursor;
+            if !page.page_info.has_next_page || after.is_none() {
+                break;
+            }
+        }
+        notifications.sort_by_key(|n| std::cmp::Reverse(n.created_at));
+
+        Ok(notifications)
+    }
+
+    pub fn mark_notification_read(&self, id: &str) -> Result<()> {
+        let variables = mark_notification_read::Variables {
+            id: id.to_string(),
+            read_at: Utc::now(),
+        };
+
+        if !self
+            .mutate::<MarkNotificationRead>(variables)?
+            .notification_update
+            .success
+        {
+            return Err(ApiError::Rejected("Notification was not marked as read"));
+        }
+
+        Ok(())
+    }
+
+    /// Marks `notifications` read, with one request per issue rather than
+    /// per notification.
+    pub fn mark_notifications_read(&self, notifications: &[Notification]) -> Result<()> {
+        let read_at = Utc::now();
+        let mut issues = HashSet::new();
+        for notification in notifications {
+            let variables = match &notification.issue {
+                Some(issue) if !issues.insert(issue.id.as_str()) => continue,
+                Some(issue) => mark_notifications_read::Variables {
+                    id: None,
+                    issue_id: Some(issue.id.clone()),
+                    read_at,
+                },
+                None => mark_notifications_read::Variables {
+                    id: Some(notification.id.clone()),
+                    issue_id: None,
+                    read_at,
+                },
+            };
+
+            if !self
+                .mutate::<MarkNotificationsRead>(variables)?
+                .notification_mark_read_all
+                .success
+            {
+                return Err(ApiError::Rejected("Notifications were not marked as read"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Hides a notification from the inbox until `until`.
+    pub fn snooze_notification(&self, id: &str, until: DateTime<Utc>) -> Result<()> {
+        let variables = snooze_notification::Variables {
+            id: id.to_string(),
+            snoozed_until_at: until,
+        };
+
+        if !self
+            .mutate::<SnoozeNotification>(variables)?
+            .notification_update
+            .success
+        {
+            return Err(ApiError::Rejected("Notification was not snoozed"));
+        }
+
+        Ok(())
+    }
+
+    pub fn archive_notification(&self, id: &str) -> Result<()> {
+        let variables = archive_notification::Variables { id: id.to_string() };
+
+        if !self
+            .mutate::<ArchiveNotification>(variables)?
+            .notification_archive
+            .success
+        {
+            return Err(ApiError::Rejected("Notification was not archived"));
+        }
+
+        Ok(())
+    }
+
+    pub fn delete_comment(&self, id: &str) -> Result<()> {
+        let variables = delete_comment::Variables { id: id.to_string() };
+
+        if !self
+            .mutate::<DeleteComment>(variables)?
+            .comment_delete
+            .success
+        {
+            return Err(ApiError::Rejected("Comment was not deleted"));
+        }
+
+        Ok(())
+    }
+
+    pub fn resolve_comment(&self, id: &str) -> Result<Comment> {
+        let variables = resolve_comment::Variables { id: id.to_string() };
+
+        let payload = self.mutate::<ResolveComment>(variables)?.comment_resolve;
+        if !payload.success {
+            return Err(ApiError::Rejected("Comment thread was not resolved"));
+        }
+
+        Ok(payload.comment.into())
+    }
+
+    pub fn unresolve_comment(&self, id: &str) -> Result<Comment> {
+        let variables = unresolve_comment::Variables { id: id.to_string() };
+
+        let payload = self
+            .mutate::<UnresolveComment>(variables)?
+            .comment_unresolve;
+        if !payload.success {
+            return Err(ApiError::Rejected("Comment thread was not unresolved"));
+        }
 
-        Ok(viewer)
+        Ok(payload.comment.into())
     }
 }
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use serde::Serialize;
+use serde_json::Value;
+use thiserror::Error;
+
+pub type Result<T, E = ApiError> = std::result::Result<T, E>;
+
+/// Everything that can go wrong talking to Linear, split by what the caller
+/// can do about it.
+#[derive(Debug, Error)]
+pub enum ApiError {
+    /// The request never got a response: DNS, TLS, connection reset, timeout.
+    #[error("Could not reach Linear: {0}")]
+    Transport(#[source] reqwest::Error),
+    /// A non-success status without a GraphQL body we could make sense of.
+    #[error("Linear responded with {status}")]
+    Http { status: StatusCode, body: String },
+    /// The API key or OAuth token is missing, revoked or expired.
+    #[error("Linear rejected the saved credentials: {0}")]
+    Unauthenticated(String),
+    #[error("{}", rate_limited_message(*.retry_after))]
+    RateLimited { retry_after: Option<Duration> },
+    /// The query reached the server but failed validation or execution.
+    #[error("{}", join_errors(.0))]
+    GraphQL(Vec<GraphQLError>),
+    #[error("Unexpected response from Linear: {0}")]
+    Decode(#[source] serde_json::Error),
+    #[error("No data in response")]
+    MissingData,
+    /// A mutation went through but Linear reported `success: false`.
+    #[error("{0}")]
+    Rejected(&'static str),
+}
+
+impl ApiError {
+    /// Short stable name of the variant, for machine-readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ApiError::Transport(_) => "transport",
+            ApiError::Http { .. } => "http",
+            ApiError::Unauthenticated(_) => "unauthenticated",
+            ApiError::RateLimited { .. } => "rate_limited",
+            ApiError::GraphQL(_) => "graphql",
+            ApiError::Decode(_) => "decode",
+            ApiError::MissingData => "missing_data",
+            ApiError::Rejected(_) => "rejected",
+        }
+    }
+
+    /// Whether sending the same request again later could succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ApiError::Transport(_) | ApiError::RateLimited { .. } => true,
+            ApiError::Http { status, .. } => status.is_server_error(),
+            _ => false,
+        }
+    }
+
+    /// Sorts a GraphQL error list into the variant callers care about. Linear
+    /// reports expired tokens and throttling as GraphQL errors with a `code`
+    /// extension, usually alongside a 400 status.
+    pub(super) fn from_graphql(errors: Vec<graphql_client::Error>) -> Self {
+        let errors: Vec<GraphQLError> = errors.into_iter().map(GraphQLError::from).collect();
+
+        if let Some(error) = errors
+            .iter()
+            .find(|e| e.code.as_deref() == Some("AUTHENTICATION_ERROR"))
+        {
+            return ApiError::Unauthenticated(error.message.clone());
+        }
+        if errors
+            .iter()
+            .any(|e| e.code.as_deref() == Some("RATELIMITED"))
+        {
+            return ApiError::RateLimited { retry_after: None };
+        }
+
+        ApiError::GraphQL(errors)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphQLError {
+    pub message: String,
+    /// Linear's machine-readable error code, e.g. `INVALID_INPUT`.
+    pub code: Option<String>,
+    /// Field path of the failing selection, e.g. `["issue", "comments"]`.
+    pub path: Vec<String>,
+    pub extensions: HashMap<String, Value>,
+}
+
+impl From<graphql_client::Error> for GraphQLError {
+    fn from(error: graphql_client::Error) -> Self {
+        let extensions = error.extensions.unwrap_or_default();
+        let extension = |key: &str| {
+            extensions
+                .get(key)
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        };
+
+        Self {
+            // Linear puts a friendlier sentence here than in `message`.
+            message: extension("userPresentableMessage").unwrap_or(error.message),
+            code: extension("code"),
+            path: error
+                .path
+                .unwrap_or_default()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            extensions,
+        }
+    }
+}
+
+impl fmt::Display for GraphQLError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if !self.path.is_empty() {
+            write!(f, " (at {})", self.path.join("."))?;
+        }
+        Ok(())
+    }
+}
+
+fn join_errors(errors: &[GraphQLError]) -> String {
+    let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    format!("GraphQL errors: {}", messages.join(", "))
+}
+
+fn rate_limited_message(retry_after: Option<Duration>) -> String {
+    match retry_after {
+        Some(wait) => format!(
+            "Rate limited by Linear, retry in {}s",
+            wait.as_secs().max(1)
+        ),
+        None => "Rate limited by Linear".to_string(),
+    }
+}
@@ -1,2 +1,5 @@
 pub mod client;
+pub mod error;
+mod queries;
+pub mod rate_limit;
 pub mod types;
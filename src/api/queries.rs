@@ -0,0 +1,503 @@
+//! Typed operations generated from `graphql/queries.graphql` by `build.rs`,
+//! plus conversions from their response shapes into the types in `api::types`.
+#![allow(
+    dead_code,
+    unused_imports,
+    non_camel_case_types,
+    clippy::needless_borrow,
+    clippy::enum_variant_names
+)]
+
+use graphql_client::{GraphQLQuery, QueryBody};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::types::{
+    Comment, CustomView, Cycle, CycleStatus, Issue, IssueCycle, IssueDetail, IssueProject,
+    IssueRef, IssueState, IssueTeam, Label, Notification, NotificationIssue, Page, PageInfo,
+    Priority, Project, ProjectDetail, ProjectHealth, ProjectState, ProjectUpdate, Team, User,
+    WorkflowState,
+};
+
+// Custom scalars are parsed while deserializing, so malformed dates surface
+// as a decode error rather than in the UI.
+type DateTime = chrono::DateTime<chrono::Utc>;
+type TimelessDate = chrono::NaiveDate;
+type JSONObject = Value;
+// Only used by filter inputs, which are sent as JSON (see `with_json_filter!`).
+type DateTimeOrDuration = String;
+type TimelessDateOrDuration = String;
+type Duration = String;
+
+include!(concat!(env!("OUT_DIR"), "/graphql_queries.rs"));
+
+// Operations taking an issue, project or cycle filter are sent through these wrappers, with
+// the filter as JSON. The generated `IssueFilter` has a field for every
+// comparator in the schema, so it only serves to validate the document;
+// `crate::filter` and Linear's custom views provide the JSON directly.
+macro_rules! with_json_filter {
+    ($(
+        $(#[$doc:meta])*
+        $name:ident($module:ident) => $variables:ident { $($field:ident: $ty:ty),* $(,)? }
+    )*) => {$(
+        $(#[$doc])*
+        pub struct $name;
+
+        #[derive(Debug, Serialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct $variables {
+            $(pub $field: $ty,)*
+        }
+
+        impl GraphQLQuery for $name {
+            type Variables = $variables;
+            type ResponseData = $module::ResponseData;
+
+            fn build_query(variables: Self::Variables) -> QueryBody<Self::Variables> {
+                QueryBody {
+                    variables,
+                    query: $module::QUERY,
+                    operation_name: $module::OPERATION_NAME,
+                }
+            }
+        }
+    )*};
+}
+
+with_json_filter! {
+    /// `GetIssues` with its `IssueFilter` given as JSON.
+    FilteredIssues(get_issues) => FilteredIssuesVariables {
+        first: i64,
+        after: Option<String>,
+        filter: Option<Value>,
+        include_archived: bool,
+    }
+
+    /// `SearchIssues` with its `IssueFilter` given as JSON.
+    FilteredSearch(search_issues) => FilteredSearchVariables {
+        term: String,
+        first: i64,
+        after: Option<String>,
+        filter: Option<Value>,
+        include_archived: bool,
+    }
+
+    /// `GetProjects` with its `ProjectFilter` given as JSON.
+    FilteredProjects(get_projects) => FilteredProjectsVariables {
+        after: Option<String>,
+        filter: Option<Value>,
+    }
+
+    /// `GetCycles` with its `CycleFilter` given as JSON.
+    FilteredCycles(get_cycles) => FilteredCyclesVariables {
+        after: Option<String>,
+        filter: Option<Value>,
+    }
+
+    /// `CreateCustomView` with the view's `IssueFilter` given as JSON.
+    FilteredCustomViewCreate(create_custom_view) => FilteredCustomViewCreateVariables {
+        name: String,
+        filter_data: Value,
+        shared: bool,
+    }
+}
+
+// Each generated operation gets its own copy of every selection set, so the
+// conversions for shapes shared between operations are stamped out per type.
+
+macro_rules! impl_from_user {
+    ($($ty:ty),* $(,)?) => {$(
+        impl From<$ty> for User {
+            fn from(user: $ty) -> Self {
+                Self {
+                    id: user.id,
+                    name: user.name,
+                    email: user.email,
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_from_issue_state {
+    ($($ty:ty),* $(,)?) => {$(
+        impl From<$ty> for IssueState {
+            fn from(state: $ty) -> Self {
+                Self {
+                    id: state.id,
+                    name: state.name,
+                    color: state.color,
+                }
+            }
+        }
+    )*};
+}
+
+/// Implements the conversions for the `IssueFields` fragment and everything
+/// nested in it, for each operation module that spreads the fragment. The
+/// `@fragment` form takes the generated types of a fragment with the same
+/// selection, such as `IssueSearchFields`.
+macro_rules! impl_from_issue_fields {
+    ($($module:ident),* $(,)?) => {$(
+        impl_from_issue_fields!(
+            @fragment $module::IssueFields,
+            $module::IssueFieldsAssignee,
+            $module::IssueFieldsCreator,
+            $module::IssueFieldsState
+        );
+    )*};
+    (@fragment $fields:ty, $assignee:ty, $creator:ty, $state:ty) => {
+        impl_from_user!($assignee, $creator);
+        impl_from_issue_state!($state);
+
+        impl From<$fields> for Issue {
+            fn from(issue: $fields) -> Self {
+                Self {
+                    id: issue.id,
+                    identifier: issue.identifier,
+                    title: issue.title,
+                    description: issue.description,
+                    state: issue.state.into(),
+                    priority: Priority::from(issue.priority as u8),
+                    assignee: issue.assignee.map(Into::into),
+                    creator: issue.creator.map(Into::into),
+                    team: IssueTeam {
+                        id: issue.team.id,
+                        name: issue.team.name,
+                        key: issue.team.key,
+                    },
+                    project: issue.project.map(|project| IssueProject {
+                        id: project.id,
+                        name: project.name,
+                    }),
+                    cycle: issue.cycle.map(|cycle| IssueCycle {
+                        id: cycle.id,
+                        number: cycle.number as u32,
+                        name: cycle.name,
+                    }),
+                    labels: issue
+                        .labels
+                        .nodes
+                        .into_iter()
+                        .map(|label| Label {
+                            id: label.id,
+                            name: label.name,
+                            color: label.color,
+                        })
+                        .collect(),
+                    estimate: issue.estimate,
+                    due_date: issue.due_date,
+                    parent: issue.parent.map(|parent| IssueRef {
+                        id: parent.id,
+                        identifier: parent.identifier,
+                    }),
+                    children: issue
+                        .children
+                        .nodes
+                        .into_iter()
+                        .map(|child| IssueRef {
+                            id: child.id,
+                            identifier: child.identifier,
+                        })
+                        .collect(),
+                    branch_name: issue.branch_name,
+                    url: issue.url,
+                    created_at: issue.created_at,
+                    updated_at: issue.updated_at,
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_from_comment {
+    ($($ty:ty),* $(,)?) => {$(
+        impl From<$ty> for Comment {
+            fn from(comment: $ty) -> Self {
+                Self {
+                    id: comment.id,
+                    body: comment.body,
+                    user: comment.user.map(Into::into),
+                    parent_id: comment.parent.map(|parent| parent.id),
+                    created_at: comment.created_at,
+                    edited_at: comment.edited_at,
+                    resolved_at: comment.resolved_at,
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_from_custom_view {
+    ($($module:ident),* $(,)?) => {$(
+        impl_from_user!($module::CustomViewFieldsOwner);
+
+        impl From<$module::CustomViewFields> for CustomView {
+            fn from(view: $module::CustomViewFields) -> Self {
+                Self {
+                    id: view.id,
+                    name: view.name,
+                    description: view.description,
+                    shared: view.shared,
+                    owner: view.owner.into(),
+                    team: view.team.map(|team| IssueTeam {
+                        id: team.id,
+                        name: team.name,
+                        key: team.key,
+                    }),
+                    filter: view.filter_data,
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_from_project_fields {
+    ($($module:ident),* $(,)?) => {$(
+        impl_from_user!($module::ProjectFieldsLead);
+
+        impl From<$module::ProjectFields> for Project {
+            fn from(project: $module::ProjectFields) -> Self {
+                Self {
+                    id: project.id,
+                    name: project.name,
+                    description: project.description,
+                    state: ProjectState {
+                        name: project.status.name,
+                        color: project.status.color,
+                    },
+                    lead: project.lead.map(Into::into),
+                    progress: project.progress,
+                    target_date: project.target_date,
+                    teams: project
+                        .teams
+                        .nodes
+                        .into_iter()
+                        .map(|team| IssueTeam {
+                            id: team.id,
+                            name: team.name,
+                            key: team.key,
+                        })
+                        .collect(),
+                    url: project.url,
+                }
+            }
+        }
+    )*};
+}
+
+impl_from_user!(
+    get_issue::CommentFieldsUser,
+    get_issue_comments::CommentFieldsUser,
+    create_comment::CommentFieldsUser,
+    update_comment::CommentFieldsUser,
+    resolve_comment::CommentFieldsUser,
+    unresolve_comment::CommentFieldsUser,
+    get_viewer::GetViewerViewer,
+    get_teams::GetTeamsTeamsEdgesNodeMembersNodes,
+    get_project::GetProjectProjectProjectUpdatesNodesUser,
+    get_notifications::GetNotificationsNotificationsNodesActor,
+);
+
+impl_from_comment!(
+    get_issue::CommentFields,
+    get_issue_comments::CommentFields,
+    create_comment::CommentFields,
+    update_comment::CommentFields,
+    resolve_comment::CommentFields,
+    unresolve_comment::CommentFields,
+);
+
+impl_from_custom_view!(get_custom_views, create_custom_view);
+
+impl_from_project_fields!(get_projects, get_project);
+
+impl_from_issue_fields!(
+    get_issues,
+    get_issue,
+    create_issue,
+    update_issue_state,
+    update_issue_priority,
+    update_issue_cycle,
+    update_issue_assignee,
+);
+
+impl_from_issue_fields!(
+    @fragment search_issues::IssueSearchFields,
+    search_issues::IssueSearchFieldsAssignee,
+    search_issues::IssueSearchFieldsCreator,
+    search_issues::IssueSearchFieldsState
+);
+
+impl From<get_issues::GetIssuesIssues> for Page<Issue> {
+    fn from(issues: get_issues::GetIssuesIssues) -> Self {
+        Self {
+            nodes: issues
+                .edges
+                .into_iter()
+                .map(|edge| edge.node.into())
+                .collect(),
+            page_info: PageInfo {
+                has_next_page: issues.page_info.has_next_page,
+                end_cursor: issues.page_info.end_cursor,
+            },
+        }
+    }
+}
+
+impl From<search_issues::SearchIssuesSearchIssues> for Page<Issue> {
+    fn from(results: search_issues::SearchIssuesSearchIssues) -> Self {
+        Self {
+            nodes: results.nodes.into_iter().map(Into::into).collect(),
+            page_info: PageInfo {
+                has_next_page: results.page_info.has_next_page,
+                end_cursor: results.page_info.end_cursor,
+            },
+        }
+    }
+}
+
+macro_rules! impl_from_comment_page {
+    ($($ty:ty),* $(,)?) => {$(
+        impl From<$ty> for Page<Comment> {
+            fn from(comments: $ty) -> Self {
+                Self {
+                    nodes: comments.edges.into_iter().map(|edge| edge.node.into()).collect(),
+                    page_info: PageInfo {
+                        has_next_page: comments.page_info.has_next_page,
+                        end_cursor: comments.page_info.end_cursor,
+                    },
+                }
+            }
+        }
+    )*};
+}
+
+impl_from_comment_page!(
+    get_issue::GetIssueIssueComments,
+    get_issue_comments::GetIssueCommentsIssueComments,
+);
+
+impl From<get_cycles::GetCyclesCyclesNodes> for Cycle {
+    fn from(cycle: get_cycles::GetCyclesCyclesNodes) -> Self {
+        // Each history has an entry per day of the cycle; the last is today's.
+        let latest = |history: &[f64]| history.last().copied().unwrap_or(0.0);
+
+        Self {
+            id: cycle.id,
+            number: cycle.number as u32,
+            name: cycle.name,
+            team: IssueTeam {
+                id: cycle.team.id,
+                name: cycle.team.name,
+                key: cycle.team.key,
+            },
+            status: if cycle.is_active {
+                CycleStatus::Active
+            } else if cycle.is_future {
+                CycleStatus::Upcoming
+            } else {
+                CycleStatus::Past
+            },
+            starts_at: cycle.starts_at,
+            ends_at: cycle.ends_at,
+            progress: cycle.progress,
+            issue_count: latest(&cycle.issue_count_history) as u32,
+            completed_issue_count: latest(&cycle.completed_issue_count_history) as u32,
+            scope: latest(&cycle.scope_history),
+            completed_scope: latest(&cycle.completed_scope_history),
+        }
+    }
+}
+
+impl From<get_notifications::GetNotificationsNotificationsNodes> for Notification {
+    fn from(notification: get_notifications::GetNotificationsNotificationsNodes) -> Self {
+        use get_notifications::GetNotificationsNotificationsNodesOn as On;
+
+        Self {
+            id: notification.id,
+            kind: notification.type_,
+            actor: notification.actor.map(Into::into),
+            issue: match notification.on {
+                On::IssueNotification(on) => Some(NotificationIssue {
+                    id: on.issue.id,
+                    identifier: on.issue.identifier,
+                    title: on.issue.title,
+                }),
+                _ => None,
+            },
+            created_at: notification.created_at,
+            read_at: notification.read_at,
+        }
+    }
+}
+
+impl From<get_teams::GetTeamsTeamsEdgesNodeStatesNodes> for WorkflowState {
+    fn from(state: get_teams::GetTeamsTeamsEdgesNodeStatesNodes) -> Self {
+        Self {
+            id: state.id,
+            name: state.name,
+            color: state.color,
+            state_type: state.type_,
+            position: state.position,
+        }
+    }
+}
+
+impl From<get_teams::GetTeamsTeamsEdgesNode> for Team {
+    fn from(team: get_teams::GetTeamsTeamsEdgesNode) -> Self {
+        let mut states: Vec<WorkflowState> =
+            team.states.nodes.into_iter().map(Into::into).collect();
+        states.sort_by(|a, b| {
+            a.sort_key()
+                .partial_cmp(&b.sort_key())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        Self {
+            id: team.id,
+            name: team.name,
+            key: team.key,
+            states,
+            members: team.members.nodes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<get_project::GetProjectProjectProjectUpdatesNodes> for ProjectUpdate {
+    fn from(update: get_project::GetProjectProjectProjectUpdatesNodes) -> Self {
+        use get_project::ProjectUpdateHealthType;
+
+        Self {
+            id: update.id,
+            body: update.body,
+            health: match update.health {
+                ProjectUpdateHealthType::onTrack => Some(ProjectHealth::OnTrack),
+                ProjectUpdateHealthType::atRisk => Some(ProjectHealth::AtRisk),
+                ProjectUpdateHealthType::offTrack => Some(ProjectHealth::OffTrack),
+                ProjectUpdateHealthType::Other(_) => None,
+            },
+            user: update.user.into(),
+            created_at: update.created_at,
+        }
+    }
+}
+
+impl From<get_project::GetProjectProject> for ProjectDetail {
+    fn from(project: get_project::GetProjectProject) -> Self {
+        let mut updates: Vec<ProjectUpdate> = project
+            .project_updates
+            .nodes
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        updates.sort_by_key(|update| std::cmp::Reverse(update.created_at));
+
+        Self {
+            project: project.project_fields.into(),
+            updates,
+            // Fetched separately, so that they can be paged through.
+            issues: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rand::Rng;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+/// Warn once less than this fraction of a quota is left.
+const LOW_QUOTA_RATIO: f64 = 0.1;
+const BASE_DELAY: Duration = Duration::from_millis(500);
+/// Longest we are willing to block a request for, whether backing off or
+/// waiting for a quota to refill.
+pub(super) const MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// One of Linear's rate-limit buckets, as reported by the
+/// `X-RateLimit-{Requests,Complexity}-*` response headers.
+#[derive(Debug, Clone, Copy)]
+pub struct Quota {
+    pub limit: u64,
+    pub remaining: u64,
+    /// When the bucket refills.
+    pub reset: Option<SystemTime>,
+}
+
+impl Quota {
+    fn from_headers(headers: &HeaderMap, kind: &str) -> Option<Self> {
+        let header = |name: &str| -> Option<u64> {
+            headers
+                .get(format!("x-ratelimit-{}-{}", kind, name))?
+                .to_str()
+                .ok()?
+                .parse()
+                .ok()
+        };
+
+        Some(Self {
+            limit: header("limit")?,
+            remaining: header("remaining")?,
+            // Linear sends the reset time in milliseconds since the epoch.
+            reset: header("reset").map(|ms| UNIX_EPOCH + Duration::from_millis(ms)),
+        })
+    }
+
+    pub fn is_low(&self) -> bool {
+        (self.remaining as f64) < self.limit as f64 * LOW_QUOTA_RATIO
+    }
+
+    fn until_reset(&self) -> Option<Duration> {
+        self.reset?.duration_since(SystemTime::now()).ok()
+    }
+}
+
+/// The most recent quota Linear reported for our credentials.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RateLimit {
+    pub requests: Option<Quota>,
+    pub complexity: Option<Quota>,
+}
+
+impl RateLimit {
+    pub(super) fn from_headers(headers: &HeaderMap) -> Self {
+        Self {
+            requests: Quota::from_headers(headers, "requests"),
+            complexity: Quota::from_headers(headers, "complexity"),
+        }
+    }
+
+    /// A short status-bar warning once either bucket is running low.
+    pub fn warning(&self) -> Option<String> {
+        if let Some(quota) = self.requests.filter(Quota::is_low) {
+            return Some(format!("{}/{} requests left", quota.remaining, quota.limit));
+        }
+        if let Some(quota) = self.complexity.filter(Quota::is_low) {
+            return Some(format!(
+                "{}/{} complexity points left",
+                quota.remaining, quota.limit
+            ));
+        }
+        None
+    }
+
+    /// How long Linear asked us to wait in `Retry-After`, or else until an
+    /// exhausted bucket refills.
+    pub(super) fn retry_after(&self, headers: &HeaderMap) -> Option<Duration> {
+        headers
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+            .map(Duration::from_secs)
+            .or_else(|| self.until_reset())
+    }
+
+    /// How long until an exhausted bucket refills, if we know.
+    fn until_reset(&self) -> Option<Duration> {
+        [self.requests, self.complexity]
+            .into_iter()
+            .flatten()
+            .filter(|quota| quota.remaining == 0)
+            .filter_map(|quota| quota.until_reset())
+            .max()
+    }
+}
+
+/// Exponential backoff with up to 50% random jitter, so that clones of the
+/// client retrying together don't hit the API in lockstep.
+pub(super) fn backoff(attempt: u32) -> Duration {
+    let delay = BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_DELAY);
+    let jitter = rand::rng().random_range(0..=delay.as_millis() as u64 / 2);
+    delay + Duration::from_millis(jitter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderName;
+
+    fn headers(pairs: &[(&'static str, String)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| (HeaderName::from_static(name), value.parse().unwrap()))
+            .collect()
+    }
+
+    fn millis_from_now(delay: Duration) -> String {
+        let reset = SystemTime::now() + delay;
+        reset
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+            .to_string()
+    }
+
+    #[test]
+    fn parses_both_buckets() {
+        let rate_limit = RateLimit::from_headers(&headers(&[
+            ("x-ratelimit-requests-limit", "1500".into()),
+            ("x-ratelimit-requests-remaining", "1499".into()),
+            ("x-ratelimit-requests-reset", "1700000000000".into()),
+            ("x-ratelimit-complexity-limit", "250000".into()),
+            ("x-ratelimit-complexity-remaining", "20000".into()),
+        ]));
+
+        let requests = rate_limit.requests.unwrap();
+        assert_eq!((requests.limit, requests.remaining), (1500, 1499));
+        assert_eq!(
+            requests.reset,
+            Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+        );
+        let complexity = rate_limit.complexity.unwrap();
+        assert_eq!((complexity.limit, complexity.remaining), (250_000, 20_000));
+        assert_eq!(complexity.reset, None);
+    }
+
+    #[test]
+    fn ignores_incomplete_or_malformed_buckets() {
+        let rate_limit = RateLimit::from_headers(&headers(&[
+            ("x-ratelimit-requests-limit", "1500".into()),
+            ("x-ratelimit-complexity-limit", "lots".into()),
+            ("x-ratelimit-complexity-remaining", "10".into()),
+        ]));
+
+        assert!(rate_limit.requests.is_none());
+        assert!(rate_limit.complexity.is_none());
+    }
+
+    #[test]
+    fn warns_below_a_tenth_of_the_quota() {
+        let rate_limit = RateLimit::from_headers(&headers(&[
+            ("x-ratelimit-requests-limit", "1500".into()),
+            ("x-ratelimit-requests-remaining", "150".into()),
+            ("x-ratelimit-complexity-limit", "250000".into()),
+            ("x-ratelimit-complexity-remaining", "24999".into()),
+        ]));
+
+        assert_eq!(
+            rate_limit.warning().as_deref(),
+            Some("24999/250000 complexity points left")
+        );
+        assert_eq!(RateLimit::default().warning(), None);
+    }
+
+    #[test]
+    fn retry_after_prefers_the_header() {
+        let headers = headers(&[
+            ("retry-after", "7".into()),
+            ("x-ratelimit-requests-limit", "1500".into()),
+            ("x-ratelimit-requests-remaining", "0".into()),
+            (
+                "x-ratelimit-requests-reset",
+                millis_from_now(Duration::from_secs(60)),
+            ),
+        ]);
+        let rate_limit = RateLimit::from_headers(&headers);
+
+        assert_eq!(
+            rate_limit.retry_after(&headers),
+            Some(Duration::from_secs(7))
+        );
+    }
+
+    #[test]
+    fn retry_after_falls_back_to_an_exhausted_bucket() {
+        let headers = headers(&[
+            ("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT".into()),
+            ("x-ratelimit-requests-limit", "1500".into()),
+            ("x-ratelimit-requests-remaining", "0".into()),
+            (
+                "x-ratelimit-requests-reset",
+                millis_from_now(Duration::from_secs(60)),
+            ),
+            ("x-ratelimit-complexity-limit", "250000".into()),
+            ("x-ratelimit-complexity-remaining", "100".into()),
+            (
+                "x-ratelimit-complexity-reset",
+                millis_from_now(Duration::from_secs(600)),
+            ),
+        ]);
+        let rate_limit = RateLimit::from_headers(&headers);
+
+        let wait = rate_limit.retry_after(&headers).unwrap();
+        assert!(wait > Duration::from_secs(50) && wait <= Duration::from_secs(60));
+        assert_eq!(RateLimit::default().retry_after(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn backoff_grows_with_jitter_and_is_capped() {
+        for attempt in 0..3 {
+            let base = BASE_DELAY * 2u32.pow(attempt);
+            let delay = backoff(attempt);
+            assert!(delay >= base && delay <= base + base / 2);
+        }
+        assert!(backoff(20) <= MAX_DELAY + MAX_DELAY / 2);
+    }
+}
@@ -1,4 +1,10 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Issue {
@@ -7,12 +13,77 @@ pub struct Issue {
     pub title: String,
     pub description: Option<String>,
     pub state: IssueState,
-    pub priority: u8,
+    pub priority: Priority,
     pub assignee: Option<User>,
+    pub creator: Option<User>,
+    pub team: IssueTeam,
+    pub project: Option<IssueProject>,
+    pub cycle: Option<IssueCycle>,
+    pub labels: Vec<Label>,
+    pub estimate: Option<f64>,
+    #[serde(rename = "dueDate")]
+    pub due_date: Option<NaiveDate>,
+    pub parent: Option<IssueRef>,
+    /// Sub-issues.
+    pub children: Vec<IssueRef>,
+    /// Git branch name Linear suggests for the issue.
+    #[serde(rename = "branchName")]
+    pub branch_name: String,
+    pub url: String,
     #[serde(rename = "createdAt")]
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     #[serde(rename = "updatedAt")]
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueDetail {
+    pub issue: Issue,
+    pub comments: Vec<Comment>,
+}
+
+impl IssueDetail {
+    pub fn new(issue: Issue, mut comments: Vec<Comment>) -> Self {
+        comments.sort_by_key(|c| c.created_at);
+
+        // Lay the comments out as threads: each top-level comment followed by its replies.
+        let ids: HashSet<String> = comments.iter().map(|c| c.id.clone()).collect();
+        let (replies, roots): (Vec<Comment>, Vec<Comment>) = comments
+            .into_iter()
+            .partition(|c| c.parent_id.as_ref().is_some_and(|id| ids.contains(id)));
+        let mut threaded = Vec::with_capacity(roots.len() + replies.len());
+        for root in roots {
+            let id = root.id.clone();
+            threaded.push(root);
+            threaded.extend(
+                replies
+                    .iter()
+                    .filter(|reply| reply.parent_id.as_deref() == Some(id.as_str()))
+                    .cloned(),
+            );
+        }
+
+        Self {
+            issue,
+            comments: threaded,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+    pub id: String,
+    pub body: String,
+    pub user: Option<User>,
+    /// Set on replies; points at the comment that started the thread.
+    #[serde(rename = "parentId")]
+    pub parent_id: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "editedAt")]
+    pub edited_at: Option<DateTime<Utc>>,
+    #[serde(rename = "resolvedAt")]
+    pub resolved_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +93,133 @@ pub struct IssueState {
     pub color: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Label {
+    pub id: String,
+    pub name: String,
+    pub color: String,
+}
+
+/// The team an issue belongs to, without its states and members.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueTeam {
+    pub id: String,
+    pub name: String,
+    pub key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueProject {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueCycle {
+    pub id: String,
+    pub number: u32,
+    pub name: Option<String>,
+}
+
+impl IssueCycle {
+    /// The cycle's name, or "Cycle N" for unnamed ones.
+    pub fn label(&self) -> String {
+        cycle_label(self.number, self.name.as_deref())
+    }
+}
+
+fn cycle_label(number: u32, name: Option<&str>) -> String {
+    match name {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => format!("Cycle {}", number),
+    }
+}
+
+/// A team's sprint, with how far through its issues the team is.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cycle {
+    pub id: String,
+    pub number: u32,
+    pub name: Option<String>,
+    pub team: IssueTeam,
+    pub status: CycleStatus,
+    #[serde(rename = "startsAt")]
+    pub starts_at: DateTime<Utc>,
+    #[serde(rename = "endsAt")]
+    pub ends_at: DateTime<Utc>,
+    /// Share of the scope that is done, from 0 to 1.
+    pub progress: f64,
+    #[serde(rename = "issueCount")]
+    pub issue_count: u32,
+    #[serde(rename = "completedIssueCount")]
+    pub completed_issue_count: u32,
+    /// Estimate points of the cycle's issues.
+    pub scope: f64,
+    #[serde(rename = "completedScope")]
+    pub completed_scope: f64,
+}
+
+impl Cycle {
+    /// The cycle's name, or "Cycle N" for unnamed ones.
+    pub fn label(&self) -> String {
+        cycle_label(self.number, self.name.as_deref())
+    }
+}
+
+/// Where a cycle is relative to today. Orders active cycles first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CycleStatus {
+    Active,
+    Upcoming,
+    Past,
+}
+
+impl CycleStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            CycleStatus::Active => "Active",
+            CycleStatus::Upcoming => "Upcoming",
+            CycleStatus::Past => "Past",
+        }
+    }
+}
+
+/// Points at a related issue, such as a parent or sub-issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueRef {
+    pub id: String,
+    pub identifier: String,
+}
+
+/// A column in a team's workflow, e.g. "Todo" or "In Progress".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowState {
+    pub id: String,
+    pub name: String,
+    pub color: String,
+    /// One of `triage`, `backlog`, `unstarted`, `started`, `completed` or `canceled`.
+    #[serde(rename = "type")]
+    pub state_type: String,
+    pub position: f64,
+}
+
+impl WorkflowState {
+    /// Orders states the way Linear lists them: by category, then by position.
+    pub fn sort_key(&self) -> (u8, f64) {
+        let category = match self.state_type.as_str() {
+            "triage" => 0,
+            "backlog" => 1,
+            "unstarted" => 2,
+            "started" => 3,
+            "completed" => 4,
+            "canceled" => 5,
+            _ => 6,
+        };
+        (category, self.position)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
     pub id: String,
@@ -29,17 +227,293 @@ pub struct User {
     pub email: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageInfo {
+    #[serde(rename = "hasNextPage")]
+    pub has_next_page: bool,
+    #[serde(rename = "endCursor")]
+    pub end_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub nodes: Vec<T>,
+    pub page_info: PageInfo,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Team {
     pub id: String,
     pub name: String,
     pub key: String,
+    #[serde(default)]
+    pub states: Vec<WorkflowState>,
+    #[serde(default)]
+    pub members: Vec<User>,
+}
+
+/// A view saved in Linear's web app: a named issue filter.
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomView {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Shared with the whole workspace, rather than personal to its owner.
+    pub shared: bool,
+    pub owner: User,
+    pub team: Option<IssueTeam>,
+    /// The view's `IssueFilter`.
+    pub filter: Value,
+}
+
+/// Something that happened which Linear tells the viewer about in their inbox.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: String,
+    /// Linear's notification type, e.g. `issueAssignedToYou`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Who caused it; `None` for reminders and Linear's own notifications.
+    pub actor: Option<User>,
+    /// The issue it is about; `None` for project and document notifications.
+    pub issue: Option<NotificationIssue>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "readAt")]
+    pub read_at: Option<DateTime<Utc>>,
+}
+
+impl Notification {
+    /// What happened, e.g. "mentioned you". Types this client doesn't know
+    /// are spelled out from their name.
+    pub fn summary(&self) -> String {
+        let summary = match self.kind.as_str() {
+            "issueAssignedToYou" => "assigned you",
+            "issueUnassignedFromYou" => "unassigned you",
+            "issueMention" => "mentioned you",
+            "issueCommentMention" => "mentioned you in a comment",
+            "issueNewComment" => "commented",
+            "issueCommentReaction" => "reacted to your comment",
+            "issueEmojiReaction" => "reacted to the issue",
+            "issueStatusChanged" => "changed the status",
+            "issuePriorityUrgent" => "marked it urgent",
+            "issueCreated" => "created the issue",
+            "issueDue" => "due soon",
+            "issueBlocking" => "marked it blocking",
+            "issueSubscribed" => "subscribed you",
+            kind => return words(kind.strip_prefix("issue").unwrap_or(kind)),
+        };
+        summary.to_string()
+    }
+}
+
+/// `statusChanged` → "status changed".
+fn words(camel: &str) -> String {
+    let mut words = String::new();
+    for c in camel.chars() {
+        if c.is_uppercase() && !words.is_empty() {
+            words.push(' ');
+        }
+        words.extend(c.to_lowercase());
+    }
+    words
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationIssue {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+}
+
+/// Which issues a list is fetched with.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IssueQuery {
+    /// Linear `IssueFilter`, as built by `Filter::to_issue_filter`.
+    pub filter: Option<Value>,
+    /// Full-text search term; results are ranked by Linear's search instead
+    /// of listing issues.
+    pub search: Option<String>,
+    pub include_archived: bool,
+}
+
+/// Fields for a new issue; `None` leaves the choice to Linear's team defaults.
+#[derive(Debug, Clone)]
+pub struct NewIssue {
+    pub team_id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Option<Priority>,
+    pub assignee_id: Option<String>,
+    pub state_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
     pub id: String,
     pub name: String,
-    pub description: Option<String>,
-    pub state: String,
+    pub description: String,
+    /// The project's status, e.g. "Planned" or "In Progress".
+    pub state: ProjectState,
+    pub lead: Option<User>,
+    /// Share of the project's scope that is done, from 0 to 1.
+    pub progress: f64,
+    #[serde(rename = "targetDate")]
+    pub target_date: Option<NaiveDate>,
+    /// The teams whose issues make up the project.
+    pub teams: Vec<IssueTeam>,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectState {
+    pub name: String,
+    pub color: String,
+}
+
+/// A status post on a project, e.g. a weekly "on track" update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectUpdate {
+    pub id: String,
+    pub body: String,
+    /// `None` for a health this client doesn't know about.
+    pub health: Option<ProjectHealth>,
+    pub user: User,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ProjectHealth {
+    OnTrack,
+    AtRisk,
+    OffTrack,
+}
+
+impl ProjectHealth {
+    pub fn label(self) -> &'static str {
+        match self {
+            ProjectHealth::OnTrack => "On track",
+            ProjectHealth::AtRisk => "At risk",
+            ProjectHealth::OffTrack => "Off track",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectDetail {
+    pub project: Project,
+    /// Newest first.
+    pub updates: Vec<ProjectUpdate>,
+    pub issues: Vec<Issue>,
+}
+
+/// Linear's issue priority. The API encodes it as 0 (no priority) through
+/// 4 (low), so the raw numbers sort "no priority" before urgent; compare
+/// `Priority` values instead, which order from most to least pressing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum Priority {
+    None,
+    Urgent,
+    High,
+    Medium,
+    Low,
+}
+
+impl Priority {
+    /// In the order Linear lists them in its priority menu.
+    pub const ALL: [Priority; 5] = [
+        Priority::None,
+        Priority::Urgent,
+        Priority::High,
+        Priority::Medium,
+        Priority::Low,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Priority::None => "No priority",
+            Priority::Urgent => "Urgent",
+            Priority::High => "High",
+            Priority::Medium => "Medium",
+            Priority::Low => "Low",
+        }
+    }
+
+    pub fn icon(self) -> &'static str {
+        match self {
+            Priority::None => "─",
+            Priority::Urgent => "!",
+            Priority::High => "▇",
+            Priority::Medium => "▅",
+            Priority::Low => "▂",
+        }
+    }
+
+    fn rank(self) -> u8 {
+        match self {
+            Priority::Urgent => 0,
+            Priority::High => 1,
+            Priority::Medium => 2,
+            Priority::Low => 3,
+            Priority::None => 4,
+        }
+    }
+}
+
+impl From<u8> for Priority {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Priority::Urgent,
+            2 => Priority::High,
+            3 => Priority::Medium,
+            4 => Priority::Low,
+            _ => Priority::None,
+        }
+    }
+}
+
+impl From<Priority> for u8 {
+    fn from(priority: Priority) -> Self {
+        match priority {
+            Priority::None => 0,
+            Priority::Urgent => 1,
+            Priority::High => 2,
+            Priority::Medium => 3,
+            Priority::Low => 4,
+        }
+    }
+}
+
+impl Ord for Priority {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for Priority {
+    type Err = String;
+
+    /// Accepts a name (`urgent`, `high`, `medium`, `low`, `none`) or Linear's number.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" | "no" | "no priority" | "0" => Ok(Priority::None),
+            "urgent" | "1" => Ok(Priority::Urgent),
+            "high" | "2" => Ok(Priority::High),
+            "medium" | "3" => Ok(Priority::Medium),
+            "low" | "4" => Ok(Priority::Low),
+            _ => Err(format!(
+                "unknown priority '{}' (expected none, urgent, high, medium or low)",
+                s
+            )),
+        }
+    }
 }
@@ -0,0 +1,1977 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use chrono::DateTime;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::layout::Rect;
+use serde_json::{Value, json};
+
+use crate::api::client::IssuePages;
+use crate::api::error::ApiError;
+use crate::api::types::{
+    Comment, CustomView, Cycle, CycleStatus, Issue, IssueDetail, IssueQuery, IssueState,
+    Notification, Priority, Team, User,
+};
+use crate::board::{Board, BoardBy, Lane};
+use crate::column::{self, COLUMNS, Column};
+use crate::config::{Config, SavedView};
+use crate::cycles::{CyclesAction, CyclesTab};
+use crate::filter::{Filter, TextMatch};
+use crate::form::{CommentEditor, CommentTarget, FormAction, IssueForm};
+use crate::inbox::{self, InboxAction, InboxTab};
+use crate::list::{self, GroupBy, Row, Scope, SortMode};
+use crate::picker::{Picker, PickerAction, PickerEvent, PickerItem};
+use crate::projects::{ProjectsAction, ProjectsTab};
+use crate::time;
+use crate::ui;
+use crate::worker::{Message, Worker};
+
+const PAGE_SIZE: i32 = 50;
+/// Fetch the next page once the selection gets this close to the end of the list.
+const LOAD_MORE_THRESHOLD: usize = 5;
+/// How long typing has to pause before a workspace search is sent.
+const SEARCH_DEBOUNCE: Duration = Duration::from_millis(300);
+/// How far back the Cycles tab goes, as an ISO 8601 duration before now.
+const RECENT_CYCLES: &str = "-P8W";
+
+pub enum View {
+    List,
+    Detail,
+}
+
+/// The top-level screens, switched between with the number keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tab {
+    Issues,
+    Projects,
+    Cycles,
+    Inbox,
+}
+
+impl Tab {
+    pub const ALL: [Tab; 4] = [Tab::Issues, Tab::Projects, Tab::Cycles, Tab::Inbox];
+
+    fn from_key(key: KeyCode) -> Option<Self> {
+        match key {
+            KeyCode::Char(c) => Self::ALL
+                .get(c.to_digit(10)?.checked_sub(1)? as usize)
+                .copied(),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Tab::Issues => "Issues",
+            Tab::Projects => "Projects",
+            Tab::Cycles => "Cycles",
+            Tab::Inbox => "Inbox",
+        }
+    }
+}
+
+/// Where the `/` prompt looks for matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchScope {
+    /// Only the issues already loaded.
+    Loaded,
+    /// Linear's full-text search over the whole workspace.
+    Workspace,
+    /// The whole workspace, archived issues included.
+    Archived,
+}
+
+impl SearchScope {
+    fn next(self) -> Self {
+        match self {
+            SearchScope::Loaded => SearchScope::Workspace,
+            SearchScope::Workspace => SearchScope::Archived,
+            SearchScope::Archived => SearchScope::Loaded,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SearchScope::Loaded => "loaded",
+            SearchScope::Workspace => "workspace",
+            SearchScope::Archived => "workspace + archived",
+        }
+    }
+}
+
+/// Where `V` saves the current list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveTarget {
+    /// A view in the config file, with the query, sort, grouping and columns.
+    Config,
+    /// A custom view in Linear that only the viewer can see.
+    Personal,
+    /// A custom view in Linear shared with the whole workspace.
+    Workspace,
+}
+
+impl SaveTarget {
+    fn next(self) -> Self {
+        match self {
+            SaveTarget::Config => SaveTarget::Personal,
+            SaveTarget::Personal => SaveTarget::Workspace,
+            SaveTarget::Workspace => SaveTarget::Config,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SaveTarget::Config => "config file",
+            SaveTarget::Personal => "Linear, personal",
+            SaveTarget::Workspace => "Linear, workspace",
+        }
+    }
+}
+
+/// The prompt for saving the current list as a view.
+pub struct ViewPrompt {
+    pub name: String,
+    pub target: SaveTarget,
+}
+
+/// What the error box offers besides the message itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recovery {
+    None,
+    Retry,
+    Reauthenticate,
+}
+
+pub struct ErrorBanner {
+    pub message: String,
+    pub recovery: Recovery,
+}
+
+impl ErrorBanner {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            recovery: Recovery::None,
+        }
+    }
+}
+
+impl From<ApiError> for ErrorBanner {
+    fn from(error: ApiError) -> Self {
+        let recovery = match &error {
+            ApiError::Unauthenticated(_) => Recovery::Reauthenticate,
+            error if error.is_retryable() => Recovery::Retry,
+            _ => Recovery::None,
+        };
+
+        Self {
+            message: error.to_string(),
+            recovery,
+        }
+    }
+}
+
+pub struct App {
+    pub should_quit: bool,
+    pub tab: Tab,
+    pub view: View,
+    worker: Worker,
+    issue_pages: Option<IssuePages>,
+    pub issues: Vec<Issue>,
+    /// Indices into `issues` that match the search, best match first when
+    /// the search has text, otherwise in sort order.
+    pub filtered_issues: Vec<usize>,
+    /// How each entry of `filtered_issues` matched the search text, by issue index.
+    pub text_matches: HashMap<usize, TextMatch>,
+    /// What the list shows: `filtered_issues` laid out under group headers.
+    pub rows: Vec<Row>,
+    /// Index into `rows`.
+    pub selected_index: usize,
+    pub sort: SortMode,
+    pub group_by: GroupBy,
+    /// Keys of the groups whose issues are hidden.
+    collapsed: HashSet<String>,
+    /// Set when the list is shown as a board instead.
+    pub board: Option<Board>,
+    pub loading: bool,
+    pub loading_more: bool,
+    pub error: Option<ErrorBanner>,
+    /// Set when the user asks to sign in again; `main` restarts the login flow.
+    pub reauthenticate: bool,
+    pub search_mode: bool,
+    pub search_query: String,
+    /// The last query that parsed, applied to the loaded issues as you type.
+    filter: Filter,
+    /// Why the current query doesn't parse, if it doesn't.
+    pub filter_error: Option<String>,
+    pub search_scope: SearchScope,
+    /// When to send the workspace search for what has been typed so far.
+    search_due: Option<Instant>,
+    /// What the loaded issues were fetched with.
+    server_query: IssueQuery,
+    pub detail: Option<IssueDetail>,
+    pub detail_scroll: u16,
+    /// Where the detail view's text was last drawn, to scroll it by wrapped rows.
+    detail_viewport: Rect,
+    pub selected_comment: Option<usize>,
+    pending_detail: Option<String>,
+    pub teams: Vec<Team>,
+    pub loading_teams: bool,
+    pub projects: ProjectsTab,
+    pub cycles: CyclesTab,
+    pub inbox: InboxTab,
+    /// The issue whose team's cycles are being fetched to pick one from.
+    pending_cycle_picker: Option<String>,
+    /// Key of the team the list is limited to, if any.
+    pub team: Option<String>,
+    /// Which of the viewer's issues the list is limited to.
+    pub scope: Scope,
+    pub issue_form: Option<IssueForm>,
+    pub picker: Option<Picker>,
+    pub viewer: Option<User>,
+    pub comment_editor: Option<CommentEditor>,
+    pub pending_delete: Option<String>,
+    pub columns: Vec<Column>,
+    pub views: Vec<SavedView>,
+    /// Name of the saved or Linear view last opened or saved.
+    pub current_view: Option<String>,
+    pub view_prompt: Option<ViewPrompt>,
+    /// The workspace's and the viewer's issue views from Linear, refreshed
+    /// whenever the views picker opens.
+    pub custom_views: Vec<CustomView>,
+    loading_custom_views: bool,
+    /// The Linear view the list shows; its filter applies on top of the query.
+    custom_view: Option<CustomView>,
+    pub tick: usize,
+}
+
+impl App {
+    pub fn new(worker: Worker, views: Vec<SavedView>) -> Self {
+        let mut app = Self {
+            should_quit: false,
+            tab: Tab::Issues,
+            view: View::List,
+            worker,
+            issue_pages: None,
+            issues: Vec::new(),
+            filtered_issues: Vec::new(),
+            text_matches: HashMap::new(),
+            rows: Vec::new(),
+            selected_index: 0,
+            sort: SortMode::default(),
+            group_by: GroupBy::default(),
+            collapsed: HashSet::new(),
+            board: None,
+            loading: false,
+            loading_more: false,
+            error: None,
+            reauthenticate: false,
+            search_mode: false,
+            search_query: String::new(),
+            filter: Filter::default(),
+            filter_error: None,
+            search_scope: SearchScope::Loaded,
+            search_due: None,
+            server_query: IssueQuery::default(),
+            detail: None,
+            detail_scroll: 0,
+            detail_viewport: Rect::default(),
+            selected_comment: None,
+            pending_detail: None,
+            teams: Vec::new(),
+            loading_teams: false,
+            projects: ProjectsTab::new(),
+            cycles: CyclesTab::new(),
+            inbox: InboxTab::new(),
+            pending_cycle_picker: None,
+            team: None,
+            scope: Scope::default(),
+            issue_form: None,
+            picker: None,
+            viewer: None,
+            comment_editor: None,
+            pending_delete: None,
+            columns: column::default_columns(),
+            views,
+            current_view: None,
+            view_prompt: None,
+            custom_views: Vec::new(),
+            loading_custom_views: false,
+            custom_view: None,
+            tick: 0,
+        };
+        // The list starts out limited to the default scope.
+        app.server_query.filter = app.issue_filter(true);
+        app
+    }
+
+    pub fn is_busy(&self) -> bool {
+        self.loading
+            || self.loading_more
+            || self.loading_teams
+            || self.loading_custom_views
+            || self.projects.loading
+            || self.projects.pending.is_some()
+            || self.cycles.loading
+            || self.cycles.loading_issues
+            || self.inbox.loading
+            || self.pending_cycle_picker.is_some()
+            || self.pending_detail.is_some()
+            || self.issue_form.as_ref().is_some_and(|form| form.submitting)
+            || self
+                .comment_editor
+                .as_ref()
+                .is_some_and(|editor| editor.submitting)
+    }
+
+    /// Set once Linear reports that our remaining quota is running low.
+    pub fn rate_limit_warning(&self) -> Option<String> {
+        self.worker.rate_limit().warning()
+    }
+
+    /// Whether the list is limited to `scope`; workspace searches aren't.
+    pub fn is_scoped(&self) -> bool {
+        self.scope != Scope::All && self.server_query.search.is_none()
+    }
+
+    pub fn has_more_issues(&self) -> bool {
+        self.issue_pages.as_ref().is_some_and(IssuePages::has_more)
+    }
+
+    pub fn on_tick(&mut self) {
+        self.tick = self.tick.wrapping_add(1);
+        if self.search_due.is_some_and(|due| Instant::now() >= due) {
+            self.apply_server_filter();
+        }
+    }
+
+    pub fn on_key(&mut self, event: KeyEvent) {
+        let key = event.code;
+        if let Some(form) = &mut self.issue_form {
+            match form.on_key(key, &self.teams) {
+                FormAction::Cancel => self.issue_form = None,
+                FormAction::Submit => self.submit_issue_form(),
+                FormAction::None => {}
+            }
+        } else if let Some(editor) = &mut self.comment_editor {
+            match editor.on_key(event) {
+                FormAction::Cancel => self.comment_editor = None,
+                FormAction::Submit => self.submit_comment(),
+                FormAction::None => {}
+            }
+        } else if let Some(comment_id) = self.pending_delete.take() {
+            if key == KeyCode::Char('y') {
+                self.delete_comment(comment_id);
+            }
+        } else if let Some(picker) = &mut self.picker {
+            match picker.on_key(key) {
+                PickerEvent::Cancel => self.picker = None,
+                PickerEvent::Confirm => self.confirm_picker(),
+                PickerEvent::None => {}
+            }
+        } else if let Some(prompt) = &mut self.view_prompt {
+            match key {
+                KeyCode::Esc => self.view_prompt = None,
+                KeyCode::Enter => self.save_view(),
+                KeyCode::Tab => prompt.target = prompt.target.next(),
+                KeyCode::Backspace => {
+                    prompt.name.pop();
+                }
+                KeyCode::Char(c) => prompt.name.push(c),
+                _ => {}
+            }
+        } else if self.search_mode {
+            match key {
+                KeyCode::Esc => {
+                    self.search_mode = false;
+                    self.search_query.clear();
+                    self.search_scope = SearchScope::Loaded;
+                    self.parse_filter();
+                    self.apply_server_filter();
+                }
+                KeyCode::Enter => {
+                    self.search_mode = false;
+                    self.apply_server_filter();
+                }
+                KeyCode::Tab => {
+                    self.search_scope = self.search_scope.next();
+                    self.parse_filter();
+                }
+                KeyCode::Backspace => {
+                    self.search_query.pop();
+                    self.parse_filter();
+                }
+                KeyCode::Char(c) => {
+                    self.search_query.push(c);
+                    self.parse_filter();
+                }
+                _ => {}
+            }
+        } else if key == KeyCode::Char('L')
+            && self
+                .error
+                .as_ref()
+                .is_some_and(|error| error.recovery == Recovery::Reauthenticate)
+        {
+            // The banner stands in for the list and board, so this never
+            // competes with moving a card.
+            self.reauthenticate = true;
+            self.should_quit = true;
+        } else if let View::Detail = self.view {
+            match key {
+                KeyCode::Char('q') => self.should_quit = true,
+                KeyCode::Esc | KeyCode::Backspace => {
+                    self.view = View::List;
+                    self.detail = None;
+                    self.pending_detail = None;
+                }
+                KeyCode::Up | KeyCode::Char('k') => self.scroll_detail(-1),
+                KeyCode::Down | KeyCode::Char('j') => self.scroll_detail(1),
+                KeyCode::PageUp => self.scroll_detail(-10),
+                KeyCode::PageDown => self.scroll_detail(10),
+                KeyCode::Char('g') => self.detail_scroll = 0,
+                KeyCode::Char('r') => {
+                    if let Some(issue) = self.current_issue() {
+                        self.open_issue(issue.id.clone());
+                    }
+                }
+                KeyCode::Char('s') => self.open_state_picker(),
+                KeyCode::Char('p') => self.open_priority_picker(),
+                KeyCode::Char('y') => self.open_cycle_picker(),
+                KeyCode::Tab => self.select_comment(true),
+                KeyCode::BackTab => self.select_comment(false),
+                KeyCode::Char('c') => self.open_comment_editor(),
+                KeyCode::Char('e') => self.edit_selected_comment(),
+                KeyCode::Char('d') => {
+                    if let Some(comment) = self.selected_own_comment() {
+                        self.pending_delete = Some(comment.id.clone());
+                    }
+                }
+                KeyCode::Char('x') => self.toggle_resolved(),
+                _ => {}
+            }
+        } else if let Some(tab) = Tab::from_key(key) {
+            self.switch_tab(tab);
+        } else if self.tab == Tab::Projects {
+            match key {
+                KeyCode::Char('q') => self.should_quit = true,
+                KeyCode::Char('t') => self.open_team_picker(),
+                KeyCode::Char('y') => self.open_cycle_picker(),
+                _ => match self.projects.on_key(key, &self.teams) {
+                    ProjectsAction::Open(id) => self.open_project(id),
+                    ProjectsAction::OpenIssue(id) => self.open_issue(id),
+                    ProjectsAction::Refresh => self.load_projects(),
+                    ProjectsAction::None => {}
+                },
+            }
+        } else if self.tab == Tab::Cycles {
+            match key {
+                KeyCode::Char('q') => self.should_quit = true,
+                KeyCode::Char('t') => self.open_team_picker(),
+                KeyCode::Char('y') => self.open_cycle_picker(),
+                _ => match self.cycles.on_key(key, &self.teams) {
+                    CyclesAction::Open(id) => self.open_cycle(id),
+                    CyclesAction::OpenIssue(id) => self.open_issue(id),
+                    CyclesAction::Refresh => self.load_cycles(),
+                    CyclesAction::None => {}
+                },
+            }
+        } else if self.tab == Tab::Inbox {
+            match key {
+                KeyCode::Char('q') => self.should_quit = true,
+                _ => match self.inbox.on_key(key) {
+                    InboxAction::OpenIssue {
+                        notification_id,
+                        issue_id,
+                    } => {
+                        self.mark_notification_read(notification_id);
+                        self.open_issue(issue_id);
+                    }
+                    InboxAction::MarkRead(id) => self.mark_notification_read(id),
+                    InboxAction::MarkAllRead => self.mark_all_notifications_read(),
+                    InboxAction::Archive(id) => self.archive_notification(id),
+                    InboxAction::Snooze(id) => self.open_snooze_picker(id),
+                    InboxAction::Refresh => self.load_notifications(),
+                    InboxAction::None => {}
+                },
+            }
+        } else if self.board.is_some() && self.on_board_key(key) {
+            // Handled by the board.
+        } else {
+            match key {
+                KeyCode::Char('q') => self.should_quit = true,
+                KeyCode::Char('b') => self.toggle_board(),
+                KeyCode::Enter | KeyCode::Char(' ') => self.activate_row(),
+                KeyCode::Char('n') => self.open_issue_form(),
+                KeyCode::Char('s') => self.open_state_picker(),
+                KeyCode::Char('p') => self.open_priority_picker(),
+                KeyCode::Char('y') => self.open_cycle_picker(),
+                KeyCode::Char('C') => self.open_column_picker(None),
+                KeyCode::Char('o') => self.cycle_sort(),
+                KeyCode::Char('G') => self.cycle_group_by(),
+                KeyCode::Char('t') => self.open_team_picker(),
+                KeyCode::Char('m') => self.open_scope_picker(),
+                KeyCode::Char('v') => {
+                    self.open_view_picker();
+                    self.load_custom_views();
+                }
+                KeyCode::Char('V') => {
+                    self.view_prompt = Some(ViewPrompt {
+                        name: self.current_view.clone().unwrap_or_default(),
+                        target: SaveTarget::Config,
+                    });
+                }
+                KeyCode::Char('/') => self.search_mode = true,
+                KeyCode::Up | KeyCode::Char('k') if self.selected_index > 0 => {
+                    self.selected_index -= 1;
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    let max_index = self.visible_len().saturating_sub(1);
+
+                    if self.selected_index < max_index {
+                        self.selected_index += 1;
+                    }
+
+                    if self.selected_index + LOAD_MORE_THRESHOLD >= self.visible_len() {
+                        self.load_more_issues();
+                    }
+                }
+                KeyCode::Char('r') => {
+                    self.load_issues();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub fn on_message(&mut self, message: Message) {
+        match message {
+            Message::IssuePage {
+                pages,
+                result,
+                append,
+            } => {
+                self.loading = false;
+                self.loading_more = false;
+                // The filter changed while these were loading.
+                if *pages.query() != self.server_query {
+                    self.load_issues();
+                    return;
+                }
+                self.issue_pages = Some(pages);
+
+                match result {
+                    Some(Ok(issues)) if append => self.issues.extend(issues),
+                    Some(Ok(issues)) => self.issues = issues,
+                    Some(Err(e)) => self.report(e),
+                    None if append => {}
+                    None => self.issues.clear(),
+                }
+                self.filter_issues();
+            }
+            Message::IssueDetail { id, result } => {
+                // Ignore responses for an issue the user has already navigated away from.
+                if self.pending_detail.as_deref() != Some(id.as_str()) {
+                    return;
+                }
+                self.pending_detail = None;
+
+                match *result {
+                    Ok(detail) => {
+                        self.detail = Some(detail);
+                        self.scroll_detail(0);
+                    }
+                    Err(e) => self.report(e),
+                }
+            }
+            Message::Teams(result) => {
+                self.loading_teams = false;
+
+                match result {
+                    Ok(teams) => {
+                        self.teams = teams;
+                        self.projects.arrange_rows(&self.teams);
+                        self.cycles.arrange_rows(&self.teams);
+                        if let Some(key) = self.team.clone()
+                            && !self.teams.iter().any(|team| team.key == key)
+                        {
+                            self.set_team(None);
+                            self.error =
+                                Some(ErrorBanner::new(format!("No team with key '{}'", key)));
+                        }
+                        // A form opened before the teams arrived.
+                        self.preselect_form_team();
+                        // State order comes from the teams' workflows.
+                        self.filter_issues();
+                    }
+                    Err(e) => {
+                        if let Some(form) = &mut self.issue_form {
+                            form.error = Some(e.to_string());
+                        } else {
+                            self.report(e);
+                        }
+                    }
+                }
+            }
+            Message::IssueCreated(result) => match *result {
+                Ok(issue) => {
+                    self.issue_form = None;
+                    self.issues.insert(0, issue);
+                    self.filter_issues();
+                }
+                Err(e) => match &mut self.issue_form {
+                    Some(form) => {
+                        form.submitting = false;
+                        form.error = Some(e.to_string());
+                    }
+                    // The form was closed while the issue was being created.
+                    None => self.report(e),
+                },
+            },
+            Message::IssueUpdated(result) => match *result {
+                Ok(issue) => self.replace_issue(issue),
+                Err(e) => self.report(e),
+            },
+            Message::IssueMoved { previous, result } => match *result {
+                Ok(issue) => self.replace_issue(issue),
+                Err(e) => {
+                    self.replace_issue(*previous);
+                    self.report(e);
+                }
+            },
+            Message::Viewer(result) => match result {
+                Ok(viewer) => {
+                    self.viewer = Some(viewer);
+                    // `assignee:me` matches nothing until we know who "me" is.
+                    self.filter_issues();
+                }
+                Err(e) => self.report(e),
+            },
+            Message::Projects { team, result } => {
+                // The team changed while these were loading.
+                if team != self.team {
+                    return;
+                }
+                self.projects.loading = false;
+
+                match result {
+                    Ok(projects) => self.projects.set_projects(projects),
+                    Err(e) => self.report(e),
+                }
+            }
+            Message::ProjectDetail { id, result } => {
+                if self.projects.pending.as_deref() != Some(id.as_str()) {
+                    return;
+                }
+                self.projects.pending = None;
+
+                match *result {
+                    Ok(detail) => self.projects.set_detail(detail, &self.teams),
+                    Err(e) => self.report(e),
+                }
+            }
+            Message::Cycles { team, result } => {
+                if team != self.team {
+                    return;
+                }
+                self.cycles.loading = false;
+
+                match result {
+                    Ok(cycles) => self.cycles.set_cycles(cycles),
+                    Err(e) => self.report(e),
+                }
+            }
+            Message::CycleIssues { id, result } => {
+                if self.cycles.open.as_deref() != Some(id.as_str()) {
+                    return;
+                }
+
+                match result {
+                    Ok(issues) => self.cycles.set_issues(issues, &self.teams),
+                    Err(e) => {
+                        self.cycles.loading_issues = false;
+                        self.report(e);
+                    }
+                }
+            }
+            Message::CycleChoices { issue_id, result } => {
+                if self.pending_cycle_picker.as_deref() != Some(issue_id.as_str()) {
+                    return;
+                }
+                self.pending_cycle_picker = None;
+
+                match result {
+                    Ok(cycles) => self.show_cycle_picker(cycles),
+                    Err(e) => self.report(e),
+                }
+            }
+            Message::Notifications(result) => {
+                self.inbox.loading = false;
+
+                match result {
+                    Ok(notifications) => self.inbox.set_notifications(notifications),
+                    Err(e) => self.report(e),
+                }
+            }
+            Message::NotificationRead { id, result } => match result {
+                Ok(()) => self.inbox.mark_read(&id),
+                Err(e) => self.report(e),
+            },
+            Message::NotificationsRead { ids, result } => match result {
+                Ok(()) => ids.iter().for_each(|id| self.inbox.mark_read(id)),
+                Err(e) => self.report(e),
+            },
+            Message::NotificationDismissed { id, result } => match result {
+                Ok(()) => self.inbox.remove(&id),
+                Err(e) => self.report(e),
+            },
+            Message::CustomViews(result) => {
+                self.loading_custom_views = false;
+
+                match result {
+                    Ok(views) => {
+                        self.custom_views = views;
+                        if matches!(
+                            self.picker.as_ref().map(|picker| &picker.action),
+                            Some(PickerAction::OpenView)
+                        ) {
+                            self.open_view_picker();
+                        }
+                    }
+                    Err(e) => self.report(e),
+                }
+            }
+            Message::CustomViewCreated(result) => match *result {
+                Ok(view) => {
+                    let id = view.id.clone();
+                    self.custom_views.push(view);
+                    self.open_custom_view(&id);
+                }
+                Err(e) => self.report(e),
+            },
+            Message::CommentSaved { issue_id, result } => match *result {
+                Ok(comment) => {
+                    if self
+                        .comment_editor
+                        .as_ref()
+                        .is_some_and(|editor| editor.submitting)
+                    {
+                        self.comment_editor = None;
+                    }
+                    self.upsert_comment(&issue_id, comment);
+                }
+                Err(e) => match &mut self.comment_editor {
+                    Some(editor) if editor.submitting => {
+                        editor.submitting = false;
+                        editor.error = Some(e.to_string());
+                    }
+                    _ => self.report(e),
+                },
+            },
+            Message::CommentDeleted {
+                issue_id,
+                comment_id,
+                result,
+            } => match result {
+                Ok(()) => {
+                    let Some(detail) = self.detail.as_mut().filter(|d| d.issue.id == issue_id)
+                    else {
+                        return;
+                    };
+                    detail.comments.retain(|comment| comment.id != comment_id);
+                    self.selected_comment = self
+                        .selected_comment
+                        .filter(|_| !detail.comments.is_empty())
+                        .map(|i| i.min(detail.comments.len() - 1));
+                }
+                Err(e) => self.report(e),
+            },
+        }
+    }
+
+    fn report(&mut self, error: ApiError) {
+        self.error = Some(error.into());
+    }
+
+    /// Updates a comment in place, or adds a new one at the end of its thread.
+    fn upsert_comment(&mut self, issue_id: &str, comment: Comment) {
+        let Some(detail) = self.detail.as_mut().filter(|d| d.issue.id == issue_id) else {
+            return;
+        };
+
+        if let Some(existing) = detail.comments.iter_mut().find(|c| c.id == comment.id) {
+            *existing = comment;
+            return;
+        }
+
+        let index = match comment.parent_id.as_deref() {
+            Some(parent_id) => detail
+                .comments
+                .iter()
+                .rposition(|c| c.id == parent_id || c.parent_id.as_deref() == Some(parent_id))
+                .map_or(detail.comments.len(), |i| i + 1),
+            None => detail.comments.len(),
+        };
+        detail.comments.insert(index, comment);
+        self.selected_comment = Some(index);
+        self.scroll_to_selected_comment();
+    }
+
+    fn replace_issue(&mut self, issue: Issue) {
+        if let Some(detail) = &mut self.detail
+            && detail.issue.id == issue.id
+        {
+            detail.issue = issue.clone();
+        }
+        self.projects.replace_issue(&issue, &self.teams);
+        self.cycles.replace_issue(&issue, &self.teams);
+        if self.tab == Tab::Cycles {
+            // Completed and scoped counts may have changed.
+            self.load_cycles();
+        }
+        if let Some(existing) = self.issues.iter_mut().find(|i| i.id == issue.id) {
+            *existing = issue;
+            // The new state or priority may move it to another place or group.
+            self.filter_issues();
+        }
+    }
+
+    fn selected_issue(&self) -> Option<&Issue> {
+        if let Some(board) = &self.board {
+            return self.issues.get(board.selected()?);
+        }
+        match self.rows.get(self.selected_index)? {
+            Row::Issue(i) => self.issues.get(*i),
+            Row::Group { .. } => None,
+        }
+    }
+
+    fn cycle_sort(&mut self) {
+        self.sort = self.sort.next();
+        if self.sort == SortMode::State {
+            self.load_teams();
+        }
+        self.filter_issues();
+    }
+
+    fn cycle_group_by(&mut self) {
+        self.group_by = self.group_by.next();
+        self.collapsed.clear();
+        if self.group_by == GroupBy::State {
+            self.load_teams();
+        }
+        self.filter_issues();
+    }
+
+    /// Keys that mean something else on the board than in the list. Returns
+    /// false for the rest, which work the same in both.
+    fn on_board_key(&mut self, key: KeyCode) -> bool {
+        let Some(board) = &mut self.board else {
+            return false;
+        };
+        match key {
+            KeyCode::Left | KeyCode::Char('h') => board.left(),
+            KeyCode::Right | KeyCode::Char('l') => board.right(),
+            KeyCode::Up | KeyCode::Char('k') => board.up(),
+            KeyCode::Down | KeyCode::Char('j') => {
+                board.down();
+                if board.remaining() < LOAD_MORE_THRESHOLD {
+                    self.load_more_issues();
+                }
+            }
+            KeyCode::Char('H') => self.move_issue(-1),
+            KeyCode::Char('L') => self.move_issue(1),
+            KeyCode::Char('G') => {
+                board.by = board.by.next();
+                self.arrange_rows();
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                if let Some(issue) = self.selected_issue() {
+                    self.open_issue(issue.id.clone());
+                }
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn toggle_board(&mut self) {
+        self.board = match self.board {
+            Some(_) => None,
+            None => Some(Board::new(BoardBy::default())),
+        };
+        // Columns come from the teams' workflow states and members.
+        self.load_teams();
+        self.arrange_rows();
+    }
+
+    /// Moves the selected issue `step` columns along the board. The board
+    /// shows the move straight away and puts the issue back if Linear
+    /// rejects it.
+    fn move_issue(&mut self, step: isize) {
+        let Some(board) = &self.board else {
+            return;
+        };
+        let (Some(index), Some(lane)) = (board.selected(), board.neighbour(step)) else {
+            return;
+        };
+        let previous = self.issues[index].clone();
+        let mut moved = previous.clone();
+
+        let update = match lane {
+            Lane::State { name, .. } => {
+                let Some(state) = self
+                    .teams
+                    .iter()
+                    .find(|team| team.id == previous.team.id)
+                    .and_then(|team| team.states.iter().find(|state| state.name == *name))
+                else {
+                    self.error = Some(ErrorBanner::new(format!(
+                        "{} has no '{}' state",
+                        previous.team.key, name
+                    )));
+                    return;
+                };
+                moved.state = IssueState {
+                    id: state.id.clone(),
+                    name: state.name.clone(),
+                    color: state.color.clone(),
+                };
+                BoardUpdate::State(state.id.clone())
+            }
+            Lane::Assignee(user) => {
+                moved.assignee = user.clone();
+                BoardUpdate::Assignee(user.as_ref().map(|user| user.id.clone()))
+            }
+        };
+
+        self.issues[index] = moved;
+        self.filter_issues();
+
+        let id = previous.id.clone();
+        self.worker.spawn(move |client| {
+            let result = match update {
+                BoardUpdate::State(state_id) => client.update_issue_state(&id, &state_id),
+                BoardUpdate::Assignee(assignee_id) => {
+                    client.update_issue_assignee(&id, assignee_id.as_deref())
+                }
+            };
+            Message::IssueMoved {
+                previous: Box::new(previous),
+                result: Box::new(result),
+            }
+        });
+    }
+
+    /// Opens the selected issue, or folds and unfolds the selected group.
+    fn activate_row(&mut self) {
+        match self.rows.get(self.selected_index) {
+            Some(Row::Group { key, .. }) => {
+                if !self.collapsed.remove(key) {
+                    self.collapsed.insert(key.clone());
+                }
+                self.arrange_rows();
+            }
+            Some(Row::Issue(_)) => {
+                if let Some(issue) = self.selected_issue() {
+                    self.open_issue(issue.id.clone());
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// The issue that actions apply to: the open one in the detail view, otherwise the selected row.
+    fn current_issue(&self) -> Option<&Issue> {
+        match (&self.view, self.tab) {
+            (View::Detail, _) => self.detail.as_ref().map(|detail| &detail.issue),
+            (View::List, Tab::Issues) => self.selected_issue(),
+            (View::List, Tab::Projects) => self.projects.selected_issue(),
+            (View::List, Tab::Cycles) => self.cycles.selected_issue(),
+            (View::List, Tab::Inbox) => None,
+        }
+    }
+
+    fn open_state_picker(&mut self) {
+        let Some(issue) = self.current_issue() else {
+            return;
+        };
+        let Some(team) = self
+            .teams
+            .iter()
+            .find(|team| team.states.iter().any(|state| state.id == issue.state.id))
+        else {
+            if !self.loading_teams {
+                self.error = Some(ErrorBanner::new(
+                    "No workflow states found for this issue's team",
+                ));
+            }
+            return;
+        };
+
+        let items = team
+            .states
+            .iter()
+            .map(|state| PickerItem {
+                id: state.id.clone(),
+                label: state.name.clone(),
+                color: Some(state.color.clone()),
+            })
+            .collect();
+        let action = PickerAction::SetState {
+            issue_id: issue.id.clone(),
+        };
+
+        self.picker = Some(
+            Picker::new(format!("Move {}", issue.identifier), items, action)
+                .with_selected(&issue.state.id),
+        );
+    }
+
+    fn open_priority_picker(&mut self) {
+        let Some(issue) = self.current_issue() else {
+            return;
+        };
+
+        let items = Priority::ALL
+            .into_iter()
+            .map(|priority| PickerItem {
+                id: u8::from(priority).to_string(),
+                label: format!("{} {}", priority.icon(), priority.label()),
+                color: None,
+            })
+            .collect();
+        let action = PickerAction::SetPriority {
+            issue_id: issue.id.clone(),
+        };
+
+        self.picker = Some(
+            Picker::new(format!("Priority of {}", issue.identifier), items, action)
+                .with_selected(&u8::from(issue.priority).to_string()),
+        );
+    }
+
+    fn open_column_picker(&mut self, selected: Option<Column>) {
+        let items = COLUMNS
+            .into_iter()
+            .map(|column| {
+                let mark = if self.columns.contains(&column) {
+                    "[x]"
+                } else {
+                    "[ ]"
+                };
+                PickerItem {
+                    id: column.key().to_string(),
+                    label: format!("{} {}", mark, column.title()),
+                    color: None,
+                }
+            })
+            .collect();
+
+        let picker = Picker::new("Columns", items, PickerAction::ToggleColumn);
+        self.picker = Some(match selected {
+            Some(column) => picker.with_selected(column.key()),
+            None => picker,
+        });
+    }
+
+    fn toggle_column(&mut self, column: Column) {
+        if let Some(i) = self.columns.iter().position(|&c| c == column) {
+            self.columns.remove(i);
+        } else {
+            // Keep the columns in their canonical order whatever order they are enabled in.
+            self.columns.push(column);
+            self.columns
+                .sort_by_key(|c| COLUMNS.iter().position(|other| other == c));
+        }
+    }
+
+    fn confirm_picker(&mut self) {
+        let Some(picker) = self.picker.take() else {
+            return;
+        };
+        let Some(item_id) = picker.selected_item().map(|item| item.id.clone()) else {
+            return;
+        };
+
+        match picker.action {
+            PickerAction::SetState { issue_id } => {
+                self.worker.spawn(move |client| {
+                    Message::IssueUpdated(Box::new(client.update_issue_state(&issue_id, &item_id)))
+                });
+            }
+            PickerAction::SetPriority { issue_id } => {
+                let priority = item_id.parse::<Priority>().unwrap_or(Priority::None);
+                self.worker.spawn(move |client| {
+                    Message::IssueUpdated(Box::new(
+                        client.update_issue_priority(&issue_id, priority),
+                    ))
+                });
+            }
+            PickerAction::ToggleColumn => {
+                if let Some(column) = Column::from_key(&item_id) {
+                    self.toggle_column(column);
+                    self.open_column_picker(Some(column));
+                }
+            }
+            PickerAction::SetCycle { issue_id } => {
+                self.worker.spawn(move |client| {
+                    let cycle_id = Some(item_id.as_str()).filter(|id| !id.is_empty());
+                    Message::IssueUpdated(Box::new(client.update_issue_cycle(&issue_id, cycle_id)))
+                });
+            }
+            PickerAction::SetScope => {
+                if let Ok(scope) = item_id.parse() {
+                    self.set_scope(scope);
+                }
+            }
+            PickerAction::SetTeam if item_id.is_empty() => self.set_team(None),
+            PickerAction::SetTeam => self.set_team(Some(item_id)),
+            PickerAction::Snooze { notification_id } => {
+                let Ok(until) = DateTime::parse_from_rfc3339(&item_id) else {
+                    return;
+                };
+                self.worker.spawn(move |client| {
+                    let result = client.snooze_notification(&notification_id, until.to_utc());
+                    Message::NotificationDismissed {
+                        id: notification_id,
+                        result,
+                    }
+                });
+            }
+            PickerAction::OpenView => match item_id.split_once(':') {
+                Some(("saved", name)) => self.open_view(name),
+                Some(("linear", id)) => self.open_custom_view(id),
+                _ => self.reset_view(),
+            },
+        }
+    }
+
+    fn open_team_picker(&mut self) {
+        if self.teams.is_empty() {
+            if !self.loading_teams {
+                self.error = Some(ErrorBanner::new("No teams found"));
+            }
+            return;
+        }
+
+        // The empty id stands for every team.
+        let items = std::iter::once(PickerItem {
+            id: String::new(),
+            label: "All teams".to_string(),
+            color: None,
+        })
+        .chain(self.teams.iter().map(|team| PickerItem {
+            id: team.key.clone(),
+            label: format!("{}  {}", team.key, team.name),
+            color: None,
+        }))
+        .collect();
+
+        let picker = Picker::new("Team", items, PickerAction::SetTeam);
+        self.picker = Some(picker.with_selected(self.team.as_deref().unwrap_or("")));
+    }
+
+    /// Limits the lists to the team with the given key, or shows every team's.
+    pub fn set_team(&mut self, key: Option<String>) {
+        self.team = key;
+        self.apply_server_filter();
+        self.projects.set_projects(Vec::new());
+        self.cycles.set_cycles(Vec::new());
+        // Whatever is loading is for the old team, and will be dropped.
+        self.projects.loading = false;
+        self.cycles.loading = false;
+        self.switch_tab(self.tab);
+    }
+
+    fn open_scope_picker(&mut self) {
+        let items = Scope::ALL
+            .into_iter()
+            .map(|scope| PickerItem {
+                id: scope.key().to_string(),
+                label: scope.label().to_string(),
+                color: None,
+            })
+            .collect();
+
+        self.picker = Some(
+            Picker::new("Show issues", items, PickerAction::SetScope)
+                .with_selected(self.scope.key()),
+        );
+    }
+
+    pub fn set_scope(&mut self, scope: Scope) {
+        self.scope = scope;
+        self.apply_server_filter();
+    }
+
+    /// Shows a tab, refreshing its list in the background.
+    fn switch_tab(&mut self, tab: Tab) {
+        self.tab = tab;
+        match tab {
+            Tab::Issues => {}
+            Tab::Projects => self.load_projects(),
+            Tab::Cycles => self.load_cycles(),
+            Tab::Inbox => self.load_notifications(),
+        }
+    }
+
+    fn load_notifications(&mut self) {
+        self.inbox.loading = true;
+        self.error = None;
+
+        self.worker
+            .spawn(|client| Message::Notifications(client.get_notifications()));
+    }
+
+    fn mark_notification_read(&mut self, id: String) {
+        if self
+            .inbox
+            .notifications
+            .iter()
+            .any(|n| n.id == id && n.read_at.is_some())
+        {
+            return;
+        }
+        self.worker.spawn(move |client| {
+            let result = client.mark_notification_read(&id);
+            Message::NotificationRead { id, result }
+        });
+    }
+
+    fn mark_all_notifications_read(&mut self) {
+        let unread: Vec<Notification> = self
+            .inbox
+            .notifications
+            .iter()
+            .filter(|n| n.read_at.is_none())
+            .cloned()
+            .collect();
+        self.worker.spawn(move |client| {
+            let result = client.mark_notifications_read(&unread);
+            let ids = unread.into_iter().map(|n| n.id).collect();
+            Message::NotificationsRead { ids, result }
+        });
+    }
+
+    fn archive_notification(&mut self, id: String) {
+        self.worker.spawn(move |client| {
+            let result = client.archive_notification(&id);
+            Message::NotificationDismissed { id, result }
+        });
+    }
+
+    fn open_snooze_picker(&mut self, notification_id: String) {
+        let items = inbox::snooze_times()
+            .into_iter()
+            .map(|(label, until)| PickerItem {
+                id: until.to_rfc3339(),
+                label: format!("{}  ({})", label, time::local(until)),
+                color: None,
+            })
+            .collect();
+
+        self.picker = Some(Picker::new(
+            "Snooze until",
+            items,
+            PickerAction::Snooze { notification_id },
+        ));
+    }
+
+    fn load_projects(&mut self) {
+        self.projects.loading = true;
+        self.error = None;
+
+        let team = self.team.clone();
+        let filter = team
+            .as_ref()
+            .map(|key| json!({ "accessibleTeams": { "some": { "key": { "eq": key } } } }));
+        self.worker.spawn(move |client| Message::Projects {
+            team,
+            result: client.get_projects(filter),
+        });
+    }
+
+    fn open_project(&mut self, id: String) {
+        self.projects.pending = Some(id.clone());
+        self.error = None;
+
+        self.worker.spawn(move |client| {
+            let result = Box::new(client.get_project(&id));
+            Message::ProjectDetail { id, result }
+        });
+    }
+
+    /// Fetches the cycles that ended in the last few weeks or haven't yet.
+    fn load_cycles(&mut self) {
+        self.cycles.loading = true;
+        self.error = None;
+
+        let team = self.team.clone();
+        let recent = json!({ "endsAt": { "gt": RECENT_CYCLES } });
+        let filter = match &team {
+            Some(key) => json!({ "and": [recent, { "team": { "key": { "eq": key } } }] }),
+            None => recent,
+        };
+        self.worker.spawn(move |client| Message::Cycles {
+            team,
+            result: client.get_cycles(Some(filter)),
+        });
+    }
+
+    fn open_cycle(&mut self, id: String) {
+        if self.cycles.open.as_ref() != Some(&id) {
+            self.cycles.close();
+        }
+        self.cycles.open = Some(id.clone());
+        self.cycles.loading_issues = true;
+        self.error = None;
+
+        self.worker.spawn(move |client| {
+            let result = client.get_cycle_issues(&id);
+            Message::CycleIssues { id, result }
+        });
+    }
+
+    /// Fetches the current issue's team's active and upcoming cycles, to
+    /// offer moving the issue into one of them.
+    fn open_cycle_picker(&mut self) {
+        let Some(issue) = self.current_issue() else {
+            return;
+        };
+        let issue_id = issue.id.clone();
+        let filter = json!({
+            "team": { "id": { "eq": issue.team.id } },
+            "or": [{ "isActive": { "eq": true } }, { "isFuture": { "eq": true } }],
+        });
+
+        self.pending_cycle_picker = Some(issue_id.clone());
+        self.worker.spawn(move |client| Message::CycleChoices {
+            issue_id,
+            result: client.get_cycles(Some(filter)),
+        });
+    }
+
+    fn show_cycle_picker(&mut self, mut cycles: Vec<Cycle>) {
+        let Some(issue) = self.current_issue() else {
+            return;
+        };
+        // The active cycle and the one after it, or just the next one
+        // when the team is between cycles.
+        cycles.sort_by_key(|cycle| (cycle.status, cycle.starts_at));
+        let active = cycles
+            .first()
+            .is_some_and(|cycle| cycle.status == CycleStatus::Active);
+        cycles.truncate(if active { 2 } else { 1 });
+
+        let items = cycles
+            .iter()
+            .map(|cycle| PickerItem {
+                id: cycle.id.clone(),
+                label: format!(
+                    "{}: {}  ({} – {})",
+                    if cycle.status == CycleStatus::Active {
+                        "Current"
+                    } else {
+                        "Next"
+                    },
+                    cycle.label(),
+                    time::day(cycle.starts_at),
+                    time::day(cycle.ends_at)
+                ),
+                color: None,
+            })
+            .chain(std::iter::once(PickerItem {
+                id: String::new(),
+                label: "No cycle".to_string(),
+                color: None,
+            }))
+            .collect();
+        let action = PickerAction::SetCycle {
+            issue_id: issue.id.clone(),
+        };
+        let selected = issue.cycle.as_ref().map_or("", |cycle| cycle.id.as_str());
+
+        self.picker = Some(
+            Picker::new(format!("Move {} to cycle", issue.identifier), items, action)
+                .with_selected(selected),
+        );
+    }
+
+    /// Lists the saved views, then Linear's. Item ids are `saved:<name>` and
+    /// `linear:<id>`; the empty id stands for the unfiltered list. Doesn't
+    /// fetch, so it can rebuild the open picker when Linear's views arrive.
+    fn open_view_picker(&mut self) {
+        let saved = self.views.iter().map(|view| PickerItem {
+            id: format!("saved:{}", view.name),
+            label: match view.query.as_str() {
+                "" => view.name.clone(),
+                query => format!("{}  ({})", view.name, query),
+            },
+            color: None,
+        });
+        let linear = self.custom_views.iter().map(|view| PickerItem {
+            id: format!("linear:{}", view.id),
+            label: format!(
+                "{}  [{}]",
+                view.name,
+                match (&view.team, view.shared) {
+                    (_, false) => "personal",
+                    (Some(team), true) => team.key.as_str(),
+                    (None, true) => "workspace",
+                }
+            ),
+            color: None,
+        });
+        let items = std::iter::once(PickerItem {
+            id: String::new(),
+            label: "All issues".to_string(),
+            color: None,
+        })
+        .chain(saved)
+        .chain(linear)
+        .collect();
+
+        let selected = match (&self.custom_view, &self.current_view) {
+            (Some(view), _) => format!("linear:{}", view.id),
+            (None, Some(name)) => format!("saved:{}", name),
+            (None, None) => String::new(),
+        };
+        // Keep the cursor where it was when the Linear views arrive.
+        let selected = self
+            .picker
+            .as_ref()
+            .and_then(|picker| picker.selected_item())
+            .map_or(selected, |item| item.id.clone());
+
+        let picker = Picker::new("Views", items, PickerAction::OpenView);
+        self.picker = Some(picker.with_selected(&selected));
+    }
+
+    fn load_custom_views(&mut self) {
+        if self.loading_custom_views {
+            return;
+        }
+
+        self.loading_custom_views = true;
+        self.worker
+            .spawn(|client| Message::CustomViews(client.get_custom_views()));
+    }
+
+    /// Switches the list to the saved view called `name`, if there is one.
+    pub fn open_view(&mut self, name: &str) {
+        let Some(view) = self.views.iter().find(|view| view.name == name).cloned() else {
+            return;
+        };
+        self.current_view = Some(view.name);
+        self.custom_view = None;
+        self.show_view(view.query, view.sort, view.group_by, view.columns);
+    }
+
+    /// Switches the list to the issues of a Linear view. Linear keeps its own
+    /// display options, so the sort, grouping and columns stay as they are.
+    fn open_custom_view(&mut self, id: &str) {
+        let Some(view) = self.custom_views.iter().find(|view| view.id == id).cloned() else {
+            return;
+        };
+        self.current_view = Some(view.name.clone());
+        self.custom_view = Some(view);
+        self.show_view(
+            String::new(),
+            self.sort,
+            self.group_by,
+            self.columns.clone(),
+        );
+    }
+
+    fn reset_view(&mut self) {
+        self.current_view = None;
+        self.custom_view = None;
+        self.show_view(
+            String::new(),
+            SortMode::default(),
+            GroupBy::default(),
+            column::default_columns(),
+        );
+    }
+
+    fn show_view(
+        &mut self,
+        query: String,
+        sort: SortMode,
+        group_by: GroupBy,
+        columns: Vec<Column>,
+    ) {
+        self.sort = sort;
+        self.group_by = group_by;
+        self.columns = columns;
+        self.collapsed.clear();
+        self.search_query = query;
+        self.search_scope = SearchScope::Loaded;
+        self.parse_filter();
+        self.apply_server_filter();
+    }
+
+    fn save_view(&mut self) {
+        let Some(prompt) = self.view_prompt.take() else {
+            return;
+        };
+        let name = prompt.name.trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+
+        match prompt.target {
+            SaveTarget::Config => self.save_config_view(name),
+            SaveTarget::Personal => self.create_custom_view(name, false),
+            SaveTarget::Workspace => self.create_custom_view(name, true),
+        }
+    }
+
+    /// Stores the current query, sort, grouping and columns as a view called
+    /// `name`, replacing any view of the same name.
+    fn save_config_view(&mut self, name: String) {
+        let view = SavedView {
+            name: name.clone(),
+            query: self.search_query.clone(),
+            sort: self.sort,
+            group_by: self.group_by,
+            columns: self.columns.clone(),
+        };
+        match self.views.iter_mut().find(|v| v.name == name) {
+            Some(existing) => *existing = view,
+            None => self.views.push(view),
+        }
+        self.current_view = Some(name);
+        self.custom_view = None;
+
+        // Re-read the file so that credentials saved since startup are kept.
+        let saved = Config::load().and_then(|mut config| {
+            config.views = self.views.clone();
+            config.save()
+        });
+        if let Err(e) = saved {
+            self.error = Some(ErrorBanner::new(format!("Could not save views: {}", e)));
+        }
+    }
+
+    /// Creates a Linear view from the current filter, which is opened once
+    /// Linear has it. Free text only narrows the list down locally, and the
+    /// scope is personal, so neither is part of the view.
+    fn create_custom_view(&mut self, name: String, shared: bool) {
+        let filter = self.issue_filter(false).unwrap_or_else(|| json!({}));
+        self.worker.spawn(move |client| {
+            Message::CustomViewCreated(Box::new(client.create_custom_view(&name, filter, shared)))
+        });
+    }
+
+    fn select_comment(&mut self, forward: bool) {
+        let Some(detail) = &self.detail else {
+            return;
+        };
+        let count = detail.comments.len();
+        if count == 0 {
+            return;
+        }
+
+        self.selected_comment = Some(match (self.selected_comment, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(i), true) => (i + 1) % count,
+            (Some(i), false) => (i + count - 1) % count,
+        });
+        self.scroll_to_selected_comment();
+    }
+
+    fn scroll_to_selected_comment(&mut self) {
+        let (Some(detail), Some(selected)) = (&self.detail, self.selected_comment) else {
+            return;
+        };
+
+        let width = self.detail_viewport.width;
+        if let Some(&offset) = ui::comment_line_offsets(detail, width).get(selected) {
+            self.detail_scroll = offset.saturating_sub(1).try_into().unwrap_or(u16::MAX);
+            self.scroll_detail(0);
+        }
+    }
+
+    /// Records the screen size, which decides how the detail view wraps.
+    pub fn set_screen(&mut self, area: Rect) {
+        let viewport = ui::detail_viewport(area);
+        if viewport != self.detail_viewport {
+            self.detail_viewport = viewport;
+            self.scroll_detail(0);
+        }
+    }
+
+    /// Scrolls the detail view by `rows`, no further than its last row.
+    fn scroll_detail(&mut self, rows: i16) {
+        let Some(detail) = &self.detail else {
+            self.detail_scroll = 0;
+            return;
+        };
+        let height = ui::detail_height(detail, self.detail_viewport.width);
+        let bottom = height.saturating_sub(self.detail_viewport.height.into());
+        let scroll = self.detail_scroll.saturating_add_signed(rows);
+        self.detail_scroll = scroll.min(bottom.try_into().unwrap_or(u16::MAX));
+    }
+
+    /// The selected comment, if it was written by the authenticated user.
+    fn selected_own_comment(&self) -> Option<&Comment> {
+        let comment = self.detail.as_ref()?.comments.get(self.selected_comment?)?;
+        let viewer = self.viewer.as_ref()?;
+        comment
+            .user
+            .as_ref()
+            .is_some_and(|user| user.id == viewer.id)
+            .then_some(comment)
+    }
+
+    fn open_comment_editor(&mut self) {
+        if let Some(detail) = &self.detail {
+            let target = CommentTarget::New {
+                issue_id: detail.issue.id.clone(),
+            };
+            self.comment_editor = Some(CommentEditor::new(target, String::new()));
+        }
+    }
+
+    fn edit_selected_comment(&mut self) {
+        if let Some(comment) = self.selected_own_comment() {
+            let target = CommentTarget::Edit {
+                comment_id: comment.id.clone(),
+            };
+            self.comment_editor = Some(CommentEditor::new(target, comment.body.clone()));
+        }
+    }
+
+    fn submit_comment(&mut self) {
+        let (Some(editor), Some(detail)) = (&mut self.comment_editor, &self.detail) else {
+            return;
+        };
+
+        let body = editor.text.trim().to_string();
+        if body.is_empty() {
+            editor.error = Some("Comment is empty".to_string());
+            return;
+        }
+
+        editor.submitting = true;
+        editor.error = None;
+
+        let issue_id = detail.issue.id.clone();
+        match &editor.target {
+            CommentTarget::New { issue_id } => {
+                let issue_id = issue_id.clone();
+                self.worker.spawn(move |client| {
+                    let result = Box::new(client.create_comment(&issue_id, &body));
+                    Message::CommentSaved { issue_id, result }
+                });
+            }
+            CommentTarget::Edit { comment_id } => {
+                let comment_id = comment_id.clone();
+                self.worker.spawn(move |client| {
+                    let result = Box::new(client.update_comment(&comment_id, &body));
+                    Message::CommentSaved { issue_id, result }
+                });
+            }
+        }
+    }
+
+    fn delete_comment(&mut self, comment_id: String) {
+        let Some(detail) = &self.detail else {
+            return;
+        };
+
+        let issue_id = detail.issue.id.clone();
+        self.worker.spawn(move |client| {
+            let result = client.delete_comment(&comment_id);
+            Message::CommentDeleted {
+                issue_id,
+                comment_id,
+                result,
+            }
+        });
+    }
+
+    /// Resolves the thread the selected comment belongs to, or reopens it if already resolved.
+    fn toggle_resolved(&mut self) {
+        let Some(detail) = &self.detail else {
+            return;
+        };
+        let Some(comment) = self.selected_comment.and_then(|i| detail.comments.get(i)) else {
+            return;
+        };
+        let root = comment
+            .parent_id
+            .as_deref()
+            .and_then(|parent_id| detail.comments.iter().find(|c| c.id == parent_id))
+            .unwrap_or(comment);
+
+        let issue_id = detail.issue.id.clone();
+        let comment_id = root.id.clone();
+        let resolved = root.resolved_at.is_some();
+
+        self.worker.spawn(move |client| {
+            let result = if resolved {
+                client.unresolve_comment(&comment_id)
+            } else {
+                client.resolve_comment(&comment_id)
+            };
+            Message::CommentSaved {
+                issue_id,
+                result: Box::new(result),
+            }
+        });
+    }
+
+    fn open_issue(&mut self, id: String) {
+        if self.detail.as_ref().is_none_or(|d| d.issue.id != id) {
+            self.detail = None;
+            self.detail_scroll = 0;
+            self.selected_comment = None;
+        }
+        self.error = None;
+        self.view = View::Detail;
+        self.pending_detail = Some(id.clone());
+
+        self.worker.spawn(move |client| {
+            let result = Box::new(client.get_issue(&id));
+            Message::IssueDetail { id, result }
+        });
+    }
+
+    fn open_issue_form(&mut self) {
+        self.issue_form = Some(IssueForm::new());
+        self.preselect_form_team();
+        self.load_teams();
+    }
+
+    /// New issues go to the team being looked at, once the teams are known.
+    fn preselect_form_team(&mut self) {
+        let (Some(form), Some(key)) = (&mut self.issue_form, &self.team) else {
+            return;
+        };
+        if let Some(index) = self.teams.iter().position(|team| team.key == *key) {
+            form.team = index;
+        }
+    }
+
+    pub fn load_viewer(&mut self) {
+        self.worker
+            .spawn(|client| Message::Viewer(client.get_viewer()));
+    }
+
+    pub fn load_teams(&mut self) {
+        if self.loading_teams || !self.teams.is_empty() {
+            return;
+        }
+
+        self.loading_teams = true;
+        self.worker
+            .spawn(|client| Message::Teams(client.get_teams()));
+    }
+
+    fn submit_issue_form(&mut self) {
+        let Some(form) = &mut self.issue_form else {
+            return;
+        };
+
+        match form.to_new_issue(&self.teams) {
+            Ok(issue) => {
+                form.submitting = true;
+                form.error = None;
+                self.worker.spawn(move |client| {
+                    Message::IssueCreated(Box::new(client.create_issue(issue)))
+                });
+            }
+            Err(e) => form.error = Some(e),
+        }
+    }
+
+    fn visible_len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn load_issues(&mut self) {
+        if self.loading || self.loading_more {
+            return;
+        }
+
+        self.loading = true;
+        self.error = None;
+
+        let query = self.server_query.clone();
+        self.worker.spawn(move |client| {
+            let mut pages = client.issue_pages(PAGE_SIZE, query);
+            let result = pages.next();
+            Message::IssuePage {
+                pages,
+                result,
+                append: false,
+            }
+        });
+    }
+
+    fn load_more_issues(&mut self) {
+        if self.loading || self.loading_more {
+            return;
+        }
+        let Some(mut pages) = self.issue_pages.take_if(|pages| pages.has_more()) else {
+            return;
+        };
+
+        self.loading_more = true;
+
+        self.worker.spawn(move |_| {
+            let result = pages.next();
+            Message::IssuePage {
+                pages,
+                result,
+                append: true,
+            }
+        });
+    }
+
+    /// Re-parses the search prompt. A query that doesn't parse, often one
+    /// still being typed, leaves the previous filter in place.
+    fn parse_filter(&mut self) {
+        match self.search_query.parse() {
+            Ok(filter) => {
+                self.filter = filter;
+                self.filter_error = None;
+                self.filter_issues();
+                if self.search_scope != SearchScope::Loaded {
+                    self.search_due = Some(Instant::now() + SEARCH_DEBOUNCE);
+                }
+            }
+            Err(e) => self.filter_error = Some(e),
+        }
+    }
+
+    /// Refetches the issues with the current filter, so that matches beyond
+    /// the loaded pages show up too. Outside the loaded scope, free text is
+    /// sent to Linear's full-text search.
+    fn apply_server_filter(&mut self) {
+        self.search_due = None;
+        let search = match self.search_scope {
+            SearchScope::Loaded => None,
+            SearchScope::Workspace | SearchScope::Archived => self.filter.search_text(),
+        };
+        let query = IssueQuery {
+            // Searching the workspace looks beyond the issues in scope.
+            filter: self.issue_filter(search.is_none()),
+            search,
+            include_archived: self.search_scope == SearchScope::Archived,
+        };
+        if query != self.server_query {
+            self.server_query = query;
+            self.load_issues();
+        }
+    }
+
+    /// The query's filter, within the chosen team and the open Linear view,
+    /// and the chosen scope if `scoped`.
+    fn issue_filter(&self, scoped: bool) -> Option<Value> {
+        let team = self
+            .team
+            .as_ref()
+            .map(|key| json!({ "team": { "key": { "eq": key } } }));
+        let view = self.custom_view.as_ref().map(|view| view.filter.clone());
+        let mut filters: Vec<Value> = [
+            self.scope.to_issue_filter().filter(|_| scoped),
+            team,
+            view,
+            self.filter.to_issue_filter(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        match filters.len() {
+            0 => None,
+            1 => filters.pop(),
+            _ => Some(json!({ "and": filters })),
+        }
+    }
+
+    fn filter_issues(&mut self) {
+        let viewer = self.viewer.as_ref();
+        // Linear's search also matches descriptions and comments, so its
+        // results stay even when the text doesn't fuzzy-match the title.
+        let searched = self.server_query.search.is_some();
+        self.text_matches = self
+            .issues
+            .iter()
+            .enumerate()
+            .filter_map(|(i, issue)| match self.filter.matches(issue, viewer) {
+                Some(text_match) => Some((i, text_match)),
+                None if searched && self.filter.matches_fields(issue, viewer) => {
+                    Some((i, TextMatch::default()))
+                }
+                None => None,
+            })
+            .collect();
+        self.filtered_issues = (0..self.issues.len())
+            .filter(|i| self.text_matches.contains_key(i))
+            .collect();
+
+        let states = list::state_order(&self.teams);
+        list::sort(&mut self.filtered_issues, &self.issues, self.sort, &states);
+        if self.filter.is_ranked() && !searched {
+            // Stable, so equally good matches keep the chosen order.
+            self.filtered_issues
+                .sort_by_key(|i| Reverse(self.text_matches[i].score));
+        }
+        self.arrange_rows();
+    }
+
+    /// Rebuilds `rows` from `filtered_issues`, keeping the selection on the
+    /// same issue or group when it is still visible.
+    fn arrange_rows(&mut self) {
+        let selected = self.rows.get(self.selected_index).map(|row| match row {
+            Row::Issue(i) => self.issues.get(*i).map(|issue| issue.id.clone()),
+            Row::Group { key, .. } => Some(key.clone()),
+        });
+
+        let states = list::state_order(&self.teams);
+        self.rows = list::rows(
+            &self.filtered_issues,
+            &self.issues,
+            self.group_by,
+            &self.collapsed,
+            &states,
+        );
+
+        if let Some(Some(selected)) = selected
+            && let Some(index) = self.rows.iter().position(|row| match row {
+                Row::Issue(i) => self
+                    .issues
+                    .get(*i)
+                    .is_some_and(|issue| issue.id == selected),
+                Row::Group { key, .. } => *key == selected,
+            })
+        {
+            self.selected_index = index;
+        }
+        self.selected_index = self
+            .selected_index
+            .min(self.visible_len().saturating_sub(1));
+
+        if let Some(board) = &mut self.board {
+            board.arrange(
+                &self.filtered_issues,
+                &self.issues,
+                &self.teams,
+                self.team.as_deref(),
+            );
+        }
+    }
+}
+
+/// The field a board move changes, sent to Linear once the board shows it.
+enum BoardUpdate {
+    State(String),
+    Assignee(Option<String>),
+}
@@ -5,17 +5,53 @@ use crate::api::client::LinearClient;
 use crate::config::Config;
 use crate::oauth;
 
-pub fn ensure_authenticated() -> Result<LinearClient> {
-    let mut config = Config::load()?;
+#[derive(Debug, Clone, Copy)]
+pub enum AuthMethod {
+    OAuth,
+    ApiKey,
+}
+
+impl AuthMethod {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AuthMethod::OAuth => "OAuth",
+            AuthMethod::ApiKey => "API key",
+        }
+    }
+}
+
+/// Builds a client from the saved credentials, without prompting if there are none.
+pub fn stored_client() -> Result<Option<(LinearClient, AuthMethod)>> {
+    let config = Config::load()?;
 
     if let Some(token) = &config.oauth_token {
-        return LinearClient::new_with_oauth(token.access_token.clone());
+        let client = LinearClient::new_with_oauth(token.access_token.clone())?;
+        return Ok(Some((client, AuthMethod::OAuth)));
     }
 
     if let Some(api_key) = &config.api_key {
-        return LinearClient::new(api_key.clone());
+        let client = LinearClient::new(api_key.clone())?;
+        return Ok(Some((client, AuthMethod::ApiKey)));
+    }
+
+    Ok(None)
+}
+
+/// Drops the saved API key and OAuth token so the next login starts from scratch.
+pub fn forget_credentials() -> Result<()> {
+    let mut config = Config::load()?;
+    config.api_key = None;
+    config.oauth_token = None;
+    config.save()
+}
+
+pub fn ensure_authenticated() -> Result<LinearClient> {
+    if let Some((client, _)) = stored_client()? {
+        return Ok(client);
     }
 
+    let mut config = Config::load()?;
+
     println!("Choose auth method:");
     println!("1) OAuth");
     println!("2) API Key");
@@ -0,0 +1,273 @@
+use std::cmp::Ordering;
+
+use crate::api::types::{Issue, Team, User};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BoardBy {
+    #[default]
+    State,
+    Assignee,
+}
+
+impl BoardBy {
+    pub fn next(self) -> Self {
+        match self {
+            BoardBy::State => BoardBy::Assignee,
+            BoardBy::Assignee => BoardBy::State,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            BoardBy::State => "state",
+            BoardBy::Assignee => "assignee",
+        }
+    }
+}
+
+/// What the issues of a board column have in common, and what moving an
+/// issue into the column sets.
+#[derive(Debug, Clone)]
+pub enum Lane {
+    /// Workflow states of the same name are one column across teams.
+    State {
+        name: String,
+        color: String,
+    },
+    Assignee(Option<User>),
+}
+
+impl Lane {
+    pub fn label(&self) -> &str {
+        match self {
+            Lane::State { name, .. } => name,
+            Lane::Assignee(Some(user)) => &user.name,
+            Lane::Assignee(None) => "Unassigned",
+        }
+    }
+
+    pub fn color(&self) -> Option<&str> {
+        match self {
+            Lane::State { color, .. } => Some(color),
+            Lane::Assignee(_) => None,
+        }
+    }
+
+    fn contains(&self, issue: &Issue) -> bool {
+        match self {
+            Lane::State { name, .. } => issue.state.name == *name,
+            Lane::Assignee(user) => {
+                issue.assignee.as_ref().map(|u| &u.id) == user.as_ref().map(|u| &u.id)
+            }
+        }
+    }
+
+    fn same(&self, other: &Lane) -> bool {
+        match (self, other) {
+            (Lane::State { name: a, .. }, Lane::State { name: b, .. }) => a == b,
+            (Lane::Assignee(a), Lane::Assignee(b)) => {
+                a.as_ref().map(|u| &u.id) == b.as_ref().map(|u| &u.id)
+            }
+            _ => false,
+        }
+    }
+}
+
+pub struct BoardColumn {
+    pub lane: Lane,
+    /// Indices into `App::issues`, in list order.
+    pub issues: Vec<usize>,
+    /// Index into `issues`.
+    pub selected: usize,
+}
+
+/// The issue list laid out in columns, one per workflow state or assignee.
+pub struct Board {
+    pub by: BoardBy,
+    pub columns: Vec<BoardColumn>,
+    /// Index of the focused column.
+    pub focus: usize,
+}
+
+impl Board {
+    pub fn new(by: BoardBy) -> Self {
+        Self {
+            by,
+            columns: Vec::new(),
+            focus: 0,
+        }
+    }
+
+    pub fn left(&mut self) {
+        self.focus = self.focus.saturating_sub(1);
+    }
+
+    pub fn right(&mut self) {
+        if self.focus + 1 < self.columns.len() {
+            self.focus += 1;
+        }
+    }
+
+    pub fn up(&mut self) {
+        if let Some(column) = self.columns.get_mut(self.focus) {
+            column.selected = column.selected.saturating_sub(1);
+        }
+    }
+
+    pub fn down(&mut self) {
+        if let Some(column) = self.columns.get_mut(self.focus)
+            && column.selected + 1 < column.issues.len()
+        {
+            column.selected += 1;
+        }
+    }
+
+    /// How many issues of the focused column are below the selected one.
+    pub fn remaining(&self) -> usize {
+        self.columns.get(self.focus).map_or(0, |column| {
+            column.issues.len().saturating_sub(column.selected + 1)
+        })
+    }
+
+    /// Index into `App::issues` of the selected issue in the focused column.
+    pub fn selected(&self) -> Option<usize> {
+        let column = self.columns.get(self.focus)?;
+        column.issues.get(column.selected).copied()
+    }
+
+    /// The lane `step` columns away from the focused one, to move an issue to.
+    pub fn neighbour(&self, step: isize) -> Option<&Lane> {
+        let index = self.focus.checked_add_signed(step)?;
+        self.columns.get(index).map(|column| &column.lane)
+    }
+
+    /// Rebuilds the columns from `indices` into `issues`. Every state or
+    /// member of the shown teams gets a column, so that issues can be moved
+    /// into empty ones. The selected issue stays selected wherever it now is.
+    pub fn arrange(
+        &mut self,
+        indices: &[usize],
+        issues: &[Issue],
+        teams: &[Team],
+        team: Option<&str>,
+    ) {
+        let selected = self
+            .selected()
+            .and_then(|i| issues.get(i))
+            .map(|issue| issue.id.clone());
+        let focused = self
+            .columns
+            .get(self.focus)
+            .map(|column| column.lane.clone());
+        let previous = std::mem::take(&mut self.columns);
+
+        // The scoped team, or else every team with an issue on the board.
+        let shown: Vec<&Team> = teams
+            .iter()
+            .filter(|t| match team {
+                Some(key) => t.key == key,
+                None => indices.iter().any(|&i| issues[i].team.id == t.id),
+            })
+            .collect();
+        let lanes = match self.by {
+            BoardBy::State => state_lanes(&shown, indices, issues),
+            BoardBy::Assignee => assignee_lanes(&shown, indices, issues),
+        };
+
+        self.columns = lanes
+            .into_iter()
+            .map(|lane| {
+                let members: Vec<usize> = indices
+                    .iter()
+                    .copied()
+                    .filter(|&i| lane.contains(&issues[i]))
+                    .collect();
+                let selected = previous
+                    .iter()
+                    .find(|column| column.lane.same(&lane))
+                    .map_or(0, |column| column.selected)
+                    .min(members.len().saturating_sub(1));
+                BoardColumn {
+                    lane,
+                    issues: members,
+                    selected,
+                }
+            })
+            .collect();
+
+        let position = selected.and_then(|id| {
+            self.columns.iter().enumerate().find_map(|(c, column)| {
+                let row = column.issues.iter().position(|&i| issues[i].id == id)?;
+                Some((c, row))
+            })
+        });
+        if let Some((c, row)) = position {
+            self.focus = c;
+            self.columns[c].selected = row;
+        } else if let Some(index) =
+            focused.and_then(|lane| self.columns.iter().position(|c| c.lane.same(&lane)))
+        {
+            self.focus = index;
+        }
+        self.focus = self.focus.min(self.columns.len().saturating_sub(1));
+    }
+}
+
+/// The shown teams' workflow states in Linear's board order, plus the
+/// states of issues whose team hasn't loaded.
+fn state_lanes(teams: &[&Team], indices: &[usize], issues: &[Issue]) -> Vec<Lane> {
+    let mut states: Vec<(Lane, (u8, f64))> = Vec::new();
+    for state in teams.iter().flat_map(|team| &team.states) {
+        let key = state.sort_key();
+        match states
+            .iter_mut()
+            .find(|(lane, _)| lane.label() == state.name)
+        {
+            Some((_, existing)) => {
+                if key.partial_cmp(existing) == Some(Ordering::Less) {
+                    *existing = key;
+                }
+            }
+            None => states.push((
+                Lane::State {
+                    name: state.name.clone(),
+                    color: state.color.clone(),
+                },
+                key,
+            )),
+        }
+    }
+    for &i in indices {
+        let state = &issues[i].state;
+        if !states.iter().any(|(lane, _)| lane.label() == state.name) {
+            let lane = Lane::State {
+                name: state.name.clone(),
+                color: state.color.clone(),
+            };
+            states.push((lane, (u8::MAX, 0.0)));
+        }
+    }
+    states.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    states.into_iter().map(|(lane, _)| lane).collect()
+}
+
+/// Unassigned first, then the shown teams' members and anyone else with an
+/// issue on the board, by name.
+fn assignee_lanes(teams: &[&Team], indices: &[usize], issues: &[Issue]) -> Vec<Lane> {
+    let mut users: Vec<&User> = Vec::new();
+    let assignees = indices.iter().filter_map(|&i| issues[i].assignee.as_ref());
+    for user in teams.iter().flat_map(|team| &team.members).chain(assignees) {
+        if !users.iter().any(|u| u.id == user.id) {
+            users.push(user);
+        }
+    }
+    users.sort_by_key(|user| user.name.to_lowercase());
+
+    std::iter::once(Lane::Assignee(None))
+        .chain(
+            users
+                .into_iter()
+                .map(|user| Lane::Assignee(Some(user.clone()))),
+        )
+        .collect()
+}
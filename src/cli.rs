@@ -0,0 +1,467 @@
+use anyhow::{Context, Result};
+use clap::{Args, Parser, Subcommand};
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::io::{self, Read};
+use std::process;
+
+use crate::api::client::LinearClient;
+use crate::api::error::ApiError;
+use crate::api::types::{Issue, IssueDetail, IssueQuery, NewIssue, Priority, Team, WorkflowState};
+use crate::auth;
+use crate::filter::Filter;
+use crate::list::Scope;
+use crate::time;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "linear-tui",
+    about = "Terminal client for Linear",
+    version,
+    args_conflicts_with_subcommands = true
+)]
+pub struct Cli {
+    /// Print machine-readable JSON instead of human-readable text
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// Open the TUI on a saved view from the config file
+    #[arg(long)]
+    pub view: Option<String>,
+
+    /// Open the TUI on one team's issues, by team key (e.g. ENG)
+    #[arg(long, value_name = "KEY")]
+    pub team: Option<String>,
+
+    /// Which issues the TUI opens on: all, assigned (the default), created,
+    /// subscribed or recent
+    #[arg(long)]
+    pub scope: Option<Scope>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Work with lists of issues
+    #[command(subcommand)]
+    Issues(IssuesCommand),
+    /// Work with a single issue
+    #[command(subcommand)]
+    Issue(IssueCommand),
+    /// Work with comments
+    #[command(subcommand)]
+    Comment(CommentCommand),
+    /// Inspect authentication
+    #[command(subcommand)]
+    Auth(AuthCommand),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum IssuesCommand {
+    /// List issues
+    List {
+        /// Maximum number of issues to print
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+        /// Fetch every page instead of stopping at --limit
+        #[arg(long)]
+        all: bool,
+        /// Only list issues matching a query, e.g. 'assignee:me priority:>=high'
+        #[arg(long, short)]
+        filter: Option<Filter>,
+        /// Full-text search the whole workspace instead of listing issues
+        #[arg(long, short)]
+        search: Option<String>,
+        /// Include archived issues
+        #[arg(long)]
+        archived: bool,
+        /// Only list issues assigned to, created by or subscribed to by you,
+        /// or recently updated: assigned, created, subscribed or recent
+        #[arg(long)]
+        scope: Option<Scope>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum IssueCommand {
+    /// Show an issue and its comments
+    Show {
+        /// Issue identifier (e.g. ENG-123) or id
+        id: String,
+    },
+    /// Create an issue
+    Create(CreateArgs),
+    /// Update an issue
+    Update(UpdateArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct CreateArgs {
+    /// Key of the team to create the issue in (e.g. ENG)
+    #[arg(long)]
+    team: String,
+    #[arg(long)]
+    title: String,
+    #[arg(long)]
+    description: Option<String>,
+    /// none, urgent, high, medium or low
+    #[arg(long)]
+    priority: Option<Priority>,
+    /// Name or email of a team member, or "me"
+    #[arg(long)]
+    assignee: Option<String>,
+    /// Name of the workflow state
+    #[arg(long)]
+    state: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct UpdateArgs {
+    /// Issue identifier (e.g. ENG-123) or id
+    id: String,
+    /// Name of the workflow state to move the issue to
+    #[arg(long, required_unless_present = "priority")]
+    state: Option<String>,
+    /// none, urgent, high, medium or low
+    #[arg(long)]
+    priority: Option<Priority>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CommentCommand {
+    /// Add a comment to an issue
+    Add {
+        /// Issue identifier (e.g. ENG-123) or id
+        issue: String,
+        /// Comment text; pass "-" to read it from stdin
+        body: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AuthCommand {
+    /// Show which credentials are stored and who they belong to
+    Status,
+}
+
+pub fn run(command: Command, json: bool) -> Result<()> {
+    let result = dispatch(command, json);
+
+    if json && let Err(error) = &result {
+        // Scripts asking for JSON get failures in JSON too, on stderr.
+        eprintln!("{}", serde_json::to_string_pretty(&error_json(error))?);
+        process::exit(1);
+    }
+
+    result
+}
+
+fn dispatch(command: Command, json: bool) -> Result<()> {
+    match command {
+        Command::Auth(AuthCommand::Status) => auth_status(json),
+        Command::Issues(command) => {
+            let client = stored_client()?;
+            match command {
+                IssuesCommand::List {
+                    limit,
+                    all,
+                    filter,
+                    search,
+                    archived,
+                    scope,
+                } => {
+                    let issue_filter = match (
+                        scope.and_then(Scope::to_issue_filter),
+                        filter.as_ref().and_then(Filter::to_issue_filter),
+                    ) {
+                        (Some(scope), Some(filter)) => Some(json!({ "and": [scope, filter] })),
+                        (scope, filter) => scope.or(filter),
+                    };
+                    let query = IssueQuery {
+                        filter: issue_filter,
+                        search,
+                        include_archived: archived,
+                    };
+                    list_issues(&client, limit, all, query, filter.as_ref(), json)
+                }
+            }
+        }
+        Command::Issue(command) => {
+            let client = stored_client()?;
+            match command {
+                IssueCommand::Show { id } => {
+                    let detail = client.get_issue(&id)?;
+                    output(json, &detail, || print_detail(&detail))
+                }
+                IssueCommand::Create(args) => create_issue(&client, args, json),
+                IssueCommand::Update(args) => update_issue(&client, args, json),
+            }
+        }
+        Command::Comment(CommentCommand::Add { issue, body }) => {
+            let client = stored_client()?;
+            let body = if body == "-" {
+                let mut body = String::new();
+                io::stdin().read_to_string(&mut body)?;
+                body
+            } else {
+                body
+            };
+            let body = body.trim();
+            if body.is_empty() {
+                anyhow::bail!("Comment body is empty");
+            }
+
+            let issue = client.get_issue(&issue)?.issue;
+            let comment = client.create_comment(&issue.id, body)?;
+            output(json, &comment, || {
+                println!("Commented on {}", issue.identifier)
+            })
+        }
+    }
+}
+
+/// Subcommands run from scripts and hooks, so they use the saved
+/// credentials and never prompt for a login.
+fn stored_client() -> Result<LinearClient> {
+    match auth::stored_client()? {
+        Some((client, _)) => Ok(client),
+        None => anyhow::bail!("Not authenticated; run `linear-tui` to log in"),
+    }
+}
+
+fn output<T: Serialize>(json: bool, value: &T, human: impl FnOnce()) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(value)?);
+    } else {
+        human();
+    }
+    Ok(())
+}
+
+fn error_json(error: &anyhow::Error) -> Value {
+    let Some(api_error) = error.downcast_ref::<ApiError>() else {
+        return json!({ "error": format!("{:#}", error) });
+    };
+
+    let mut value = json!({
+        "error": format!("{:#}", error),
+        "kind": api_error.kind(),
+    });
+    match api_error {
+        ApiError::Http { status, .. } => value["status"] = json!(status.as_u16()),
+        ApiError::RateLimited {
+            retry_after: Some(wait),
+        } => value["retry_after_secs"] = json!(wait.as_secs()),
+        ApiError::GraphQL(errors) => value["errors"] = json!(errors),
+        _ => {}
+    }
+    value
+}
+
+fn auth_status(json: bool) -> Result<()> {
+    let Some((client, method)) = auth::stored_client()? else {
+        return output(json, &json!({ "authenticated": false }), || {
+            println!("Not authenticated")
+        });
+    };
+
+    let viewer = client.get_viewer()?;
+    let status = json!({
+        "authenticated": true,
+        "method": method.label(),
+        "user": viewer,
+    });
+    output(json, &status, || {
+        println!(
+            "Authenticated as {} <{}> via {}",
+            viewer.name,
+            viewer.email,
+            method.label()
+        )
+    })
+}
+
+fn list_issues(
+    client: &LinearClient,
+    limit: usize,
+    all: bool,
+    query: IssueQuery,
+    filter: Option<&Filter>,
+    json: bool,
+) -> Result<()> {
+    // Linear can't match free text fuzzily, so that part of the filter is
+    // applied to each page here, before counting towards --limit.
+    let text = filter.filter(|filter| filter.is_ranked());
+    let viewer = text.map(|_| client.get_viewer()).transpose()?;
+
+    let mut issues = Vec::new();
+    for page in client.issue_pages(50, query) {
+        let mut page = page?;
+        if let Some(filter) = text {
+            page.retain(|issue| filter.matches(issue, viewer.as_ref()).is_some());
+        }
+        issues.extend(page);
+        if !all && issues.len() >= limit {
+            issues.truncate(limit);
+            break;
+        }
+    }
+
+    output(json, &issues, || {
+        for issue in &issues {
+            print_issue_row(issue);
+        }
+    })
+}
+
+fn create_issue(client: &LinearClient, args: CreateArgs, json: bool) -> Result<()> {
+    let teams = client.get_teams()?;
+    let team = find_team(&teams, &args.team)?;
+
+    let assignee_id = match args.assignee.as_deref() {
+        None => None,
+        Some("me") => Some(client.get_viewer()?.id),
+        Some(query) => Some(
+            team.members
+                .iter()
+                .find(|user| {
+                    user.name.eq_ignore_ascii_case(query) || user.email.eq_ignore_ascii_case(query)
+                })
+                .with_context(|| format!("No member of {} matches '{}'", team.key, query))?
+                .id
+                .clone(),
+        ),
+    };
+    let state_id = args
+        .state
+        .as_deref()
+        .map(|name| find_state(&team.states, name).map(|state| state.id.clone()))
+        .transpose()?;
+
+    let issue = client.create_issue(NewIssue {
+        team_id: team.id.clone(),
+        title: args.title,
+        description: args.description,
+        priority: args.priority,
+        assignee_id,
+        state_id,
+    })?;
+
+    output(json, &issue, || println!("Created {}", issue.identifier))
+}
+
+fn update_issue(client: &LinearClient, args: UpdateArgs, json: bool) -> Result<()> {
+    let mut issue = client.get_issue(&args.id)?.issue;
+
+    if let Some(name) = &args.state {
+        let teams = client.get_teams()?;
+        let team = find_team(&teams, &issue.team.key)?;
+        let state = find_state(&team.states, name)?;
+        issue = client.update_issue_state(&issue.id, &state.id)?;
+    }
+    if let Some(priority) = args.priority {
+        issue = client.update_issue_priority(&issue.id, priority)?;
+    }
+
+    output(json, &issue, || {
+        println!(
+            "Updated {}: {} · {}",
+            issue.identifier,
+            issue.state.name,
+            issue.priority.label()
+        )
+    })
+}
+
+fn find_team<'a>(teams: &'a [Team], key: &str) -> Result<&'a Team> {
+    teams
+        .iter()
+        .find(|team| team.key.eq_ignore_ascii_case(key))
+        .with_context(|| format!("No team with key '{}'", key))
+}
+
+fn find_state<'a>(states: &'a [WorkflowState], name: &str) -> Result<&'a WorkflowState> {
+    states
+        .iter()
+        .find(|state| state.name.eq_ignore_ascii_case(name))
+        .with_context(|| {
+            let names: Vec<&str> = states.iter().map(|state| state.name.as_str()).collect();
+            format!(
+                "No state named '{}' (expected one of: {})",
+                name,
+                names.join(", ")
+            )
+        })
+}
+
+fn print_issue_row(issue: &Issue) {
+    let assignee = issue
+        .assignee
+        .as_ref()
+        .map(|u| u.name.as_str())
+        .unwrap_or("Unassigned");
+
+    println!(
+        "{:<10} {:<14} {:<12} {:<16} {}",
+        issue.identifier,
+        issue.state.name,
+        issue.priority.label(),
+        assignee,
+        issue.title
+    );
+}
+
+fn print_detail(detail: &IssueDetail) {
+    let issue = &detail.issue;
+
+    println!("{} {}", issue.identifier, issue.title);
+    println!();
+    println!("State:    {}", issue.state.name);
+    println!("Priority: {}", issue.priority.label());
+    println!(
+        "Assignee: {}",
+        issue
+            .assignee
+            .as_ref()
+            .map(|u| u.name.as_str())
+            .unwrap_or("Unassigned")
+    );
+    println!("Team:     {}", issue.team.key);
+    if !issue.labels.is_empty() {
+        let labels: Vec<&str> = issue.labels.iter().map(|l| l.name.as_str()).collect();
+        println!("Labels:   {}", labels.join(", "));
+    }
+    println!("Created:  {}", time::local(issue.created_at));
+    println!("Updated:  {}", time::local(issue.updated_at));
+    println!("URL:      {}", issue.url);
+
+    if let Some(description) = issue
+        .description
+        .as_deref()
+        .filter(|d| !d.trim().is_empty())
+    {
+        println!();
+        println!("{}", description);
+    }
+
+    for comment in &detail.comments {
+        let author = comment
+            .user
+            .as_ref()
+            .map(|u| u.name.as_str())
+            .unwrap_or("Unknown");
+        let indent = if comment.parent_id.is_some() {
+            "    "
+        } else {
+            ""
+        };
+
+        println!();
+        println!("{}{} · {}", indent, author, time::local(comment.created_at));
+        for line in comment.body.lines() {
+            println!("{}  {}", indent, line);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+
+/// Optional columns of the issue list, shown between the assignee and the title.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Column {
+    Team,
+    Project,
+    Cycle,
+    Labels,
+    Estimate,
+    DueDate,
+    Created,
+    Updated,
+}
+
+pub const COLUMNS: [Column; 8] = [
+    Column::Team,
+    Column::Project,
+    Column::Cycle,
+    Column::Labels,
+    Column::Estimate,
+    Column::DueDate,
+    Column::Created,
+    Column::Updated,
+];
+
+/// Columns shown until the user picks their own.
+pub fn default_columns() -> Vec<Column> {
+    vec![Column::Updated]
+}
+
+impl Column {
+    /// Stable identifier, matching the serialized name.
+    pub fn key(self) -> &'static str {
+        match self {
+            Column::Team => "team",
+            Column::Project => "project",
+            Column::Cycle => "cycle",
+            Column::Labels => "labels",
+            Column::Estimate => "estimate",
+            Column::DueDate => "due_date",
+            Column::Created => "created",
+            Column::Updated => "updated",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        COLUMNS.into_iter().find(|column| column.key() == key)
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Column::Team => "Team",
+            Column::Project => "Project",
+            Column::Cycle => "Cycle",
+            Column::Labels => "Labels",
+            Column::Estimate => "Estimate",
+            Column::DueDate => "Due date",
+            Column::Created => "Created",
+            Column::Updated => "Updated",
+        }
+    }
+
+    /// Width of the column in the list, in characters.
+    pub fn width(self) -> usize {
+        match self {
+            Column::Team => 5,
+            Column::Project => 16,
+            Column::Cycle => 10,
+            Column::Labels => 20,
+            Column::Estimate => 3,
+            Column::DueDate => 10,
+            Column::Created | Column::Updated => 8,
+        }
+    }
+}
@@ -2,10 +2,15 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::{fs, path::PathBuf};
 
-#[derive(Debug, Serialize, Deserialize)]
+use crate::column::{self, Column};
+use crate::list::{GroupBy, SortMode};
+
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Config {
     pub api_key: Option<String>,
     pub oauth_token: Option<OAuthToken>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub views: Vec<SavedView>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -15,13 +20,19 @@ pub struct OAuthToken {
     pub scope: String,
 }
 
-impl Default for Config {
-    fn default() -> Self {
-        Self {
-            api_key: None,
-            oauth_token: None,
-        }
-    }
+/// A named issue list setup, stored as a `[[views]]` table.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SavedView {
+    pub name: String,
+    /// Filter query, in the syntax of the `/` prompt.
+    #[serde(default)]
+    pub query: String,
+    #[serde(default)]
+    pub sort: SortMode,
+    #[serde(default)]
+    pub group_by: GroupBy,
+    #[serde(default = "column::default_columns")]
+    pub columns: Vec<Column>,
 }
 
 impl Config {
@@ -0,0 +1,141 @@
+use crossterm::event::KeyCode;
+
+use crate::api::types::{Cycle, CycleStatus, Issue, Team};
+use crate::list::{Row, StateGroups};
+
+pub enum CyclesAction {
+    None,
+    /// Load the issues of the cycle with this id and show them.
+    Open(String),
+    /// Show the issue with this id.
+    OpenIssue(String),
+    Refresh,
+}
+
+/// State of the Cycles tab: every team's recent and upcoming cycles, or one
+/// cycle's issues grouped by status.
+pub struct CyclesTab {
+    /// By team, then active, upcoming and past.
+    pub cycles: Vec<Cycle>,
+    pub selected: usize,
+    pub loading: bool,
+    /// Id of the open cycle.
+    pub open: Option<String>,
+    /// The open cycle's issues, once loaded.
+    pub issues: Option<StateGroups>,
+    pub loading_issues: bool,
+}
+
+impl CyclesTab {
+    pub fn new() -> Self {
+        Self {
+            cycles: Vec::new(),
+            selected: 0,
+            loading: false,
+            open: None,
+            issues: None,
+            loading_issues: false,
+        }
+    }
+
+    pub fn open_cycle(&self) -> Option<&Cycle> {
+        let id = self.open.as_deref()?;
+        self.cycles.iter().find(|cycle| cycle.id == id)
+    }
+
+    pub fn on_key(&mut self, key: KeyCode, teams: &[Team]) -> CyclesAction {
+        let Some(open) = &self.open else {
+            match key {
+                KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') if self.selected + 1 < self.cycles.len() => {
+                    self.selected += 1;
+                }
+                KeyCode::Enter => {
+                    if let Some(cycle) = self.cycles.get(self.selected) {
+                        return CyclesAction::Open(cycle.id.clone());
+                    }
+                }
+                KeyCode::Char('r') => return CyclesAction::Refresh,
+                _ => {}
+            }
+            return CyclesAction::None;
+        };
+
+        match key {
+            KeyCode::Esc | KeyCode::Backspace => self.close(),
+            KeyCode::Char('r') => return CyclesAction::Open(open.clone()),
+            _ => {}
+        }
+        let Some(issues) = &mut self.issues else {
+            return CyclesAction::None;
+        };
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => issues.up(),
+            KeyCode::Down | KeyCode::Char('j') => issues.down(),
+            KeyCode::Enter | KeyCode::Char(' ') => match issues.rows.get(issues.selected) {
+                Some(Row::Group { .. }) => issues.toggle_selected_group(teams),
+                Some(Row::Issue(_)) => {
+                    if let Some(issue) = issues.selected_issue() {
+                        return CyclesAction::OpenIssue(issue.id.clone());
+                    }
+                }
+                None => {}
+            },
+            _ => {}
+        }
+
+        CyclesAction::None
+    }
+
+    pub fn set_cycles(&mut self, mut cycles: Vec<Cycle>) {
+        // Active and upcoming cycles in the order they run; past ones newest first.
+        cycles.sort_by_key(|cycle| {
+            let start = cycle.starts_at.timestamp();
+            let order = match cycle.status {
+                CycleStatus::Past => -start,
+                CycleStatus::Active | CycleStatus::Upcoming => start,
+            };
+            (cycle.team.key.clone(), cycle.status, order)
+        });
+        self.cycles = cycles;
+        self.selected = self.selected.min(self.cycles.len().saturating_sub(1));
+    }
+
+    pub fn set_issues(&mut self, issues: Vec<Issue>, teams: &[Team]) {
+        self.loading_issues = false;
+        match &mut self.issues {
+            // Refreshed: keep the cursor and folded groups.
+            Some(groups) => {
+                groups.issues = issues;
+                groups.arrange(teams);
+            }
+            None => self.issues = Some(StateGroups::new(issues, teams)),
+        }
+    }
+
+    pub fn close(&mut self) {
+        self.open = None;
+        self.issues = None;
+        self.loading_issues = false;
+    }
+
+    pub fn selected_issue(&self) -> Option<&Issue> {
+        self.issues.as_ref()?.selected_issue()
+    }
+
+    /// Keeps the open cycle's issues in step with an updated issue, which may
+    /// have moved into or out of the cycle.
+    pub fn replace_issue(&mut self, issue: &Issue, teams: &[Team]) {
+        let (Some(open), Some(groups)) = (&self.open, &mut self.issues) else {
+            return;
+        };
+        let in_cycle = issue.cycle.as_ref().is_some_and(|cycle| cycle.id == *open);
+        groups.upsert(issue, in_cycle, teams);
+    }
+
+    pub fn arrange_rows(&mut self, teams: &[Team]) {
+        if let Some(groups) = &mut self.issues {
+            groups.arrange(teams);
+        }
+    }
+}
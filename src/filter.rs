@@ -0,0 +1,718 @@
+//! The query language of the `/` prompt, e.g.
+//! `state:"In Progress" assignee:me priority:>=high label:bug -label:wontfix updated:<7d`.
+//!
+//! Terms are separated by whitespace and must all match. A term is either
+//! free text, fuzzily matched against the identifier and title, or
+//! `field:value`. A leading `-` negates a term, and double quotes allow
+//! spaces in a value; negated text must appear verbatim in the identifier,
+//! title or description to exclude an issue. The same parsed [`Filter`] is
+//! applied to the loaded issues and sent to Linear as an `IssueFilter`.
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use chrono::{DateTime, Days, Local, Months, NaiveDate, NaiveTime, TimeDelta, Utc};
+use serde_json::{Value, json};
+
+use crate::api::types::{Issue, Priority, User};
+use crate::fuzzy;
+
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    terms: Vec<Term>,
+}
+
+#[derive(Debug, Clone)]
+struct Term {
+    negated: bool,
+    predicate: Predicate,
+}
+
+#[derive(Debug, Clone)]
+enum Predicate {
+    Text(String),
+    State(String),
+    Assignee(Person),
+    Creator(Person),
+    Priority(Op, Priority),
+    Label(String),
+    Team(String),
+    /// `None` matches issues without a project.
+    Project(Option<String>),
+    Created(Range),
+    Updated(Range),
+    Due(Range),
+}
+
+#[derive(Debug, Clone)]
+enum Person {
+    Me,
+    Nobody,
+    /// Part of a name or email address.
+    Named(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Op {
+    /// Splits a leading comparison operator off `value`; no operator means `=`.
+    fn split(value: &str) -> (Self, &str) {
+        for (prefix, op) in [
+            (">=", Op::Ge),
+            ("<=", Op::Le),
+            (">", Op::Gt),
+            ("<", Op::Lt),
+            ("=", Op::Eq),
+        ] {
+            if let Some(rest) = value.strip_prefix(prefix) {
+                return (op, rest);
+            }
+        }
+        (Op::Eq, value)
+    }
+
+    /// Whether `ordering`, of the issue's value against the query's, satisfies the operator.
+    fn accepts(self, ordering: Ordering) -> bool {
+        match self {
+            Op::Eq => ordering == Ordering::Equal,
+            Op::Lt => ordering == Ordering::Less,
+            Op::Le => ordering != Ordering::Greater,
+            Op::Gt => ordering == Ordering::Greater,
+            Op::Ge => ordering != Ordering::Less,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Unit {
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// A point in time, either absolute or relative to when the filter is applied.
+#[derive(Debug, Clone, Copy)]
+enum Moment {
+    Date(NaiveDate),
+    Offset { amount: u32, unit: Unit, past: bool },
+}
+
+impl Moment {
+    fn resolve(self) -> DateTime<Utc> {
+        match self {
+            Moment::Date(date) => {
+                let midnight = date.and_time(NaiveTime::MIN);
+                midnight
+                    .and_local_timezone(Local)
+                    .earliest()
+                    .map_or(midnight.and_utc(), |t| t.to_utc())
+            }
+            Moment::Offset { amount, unit, past } => {
+                let now = Utc::now();
+                let moved = match unit {
+                    Unit::Hour => shift(now, TimeDelta::hours(amount.into()), past),
+                    Unit::Day => shift(now, TimeDelta::days(amount.into()), past),
+                    Unit::Week => shift(now, TimeDelta::weeks(amount.into()), past),
+                    Unit::Month if past => now.checked_sub_months(Months::new(amount)),
+                    Unit::Month => now.checked_add_months(Months::new(amount)),
+                    Unit::Year if past => now.checked_sub_months(Months::new(amount * 12)),
+                    Unit::Year => now.checked_add_months(Months::new(amount * 12)),
+                };
+                moved.unwrap_or(now)
+            }
+        }
+    }
+
+    fn resolve_date(self) -> NaiveDate {
+        match self {
+            Moment::Date(date) => date,
+            offset => offset.resolve().with_timezone(&Local).date_naive(),
+        }
+    }
+
+    /// Renders the moment as Linear's `DateTimeOrDuration`: an ISO 8601
+    /// timestamp, or a duration such as `-P7D` relative to now.
+    fn to_graphql(self, timeless: bool) -> String {
+        match self {
+            Moment::Date(date) if timeless => date.to_string(),
+            Moment::Date(_) => self.resolve().to_rfc3339(),
+            Moment::Offset { amount, unit, past } => {
+                let sign = if past { "-" } else { "" };
+                match unit {
+                    Unit::Hour => format!("{}PT{}H", sign, amount),
+                    Unit::Day => format!("{}P{}D", sign, amount),
+                    Unit::Week => format!("{}P{}W", sign, amount),
+                    Unit::Month => format!("{}P{}M", sign, amount),
+                    Unit::Year => format!("{}P{}Y", sign, amount),
+                }
+            }
+        }
+    }
+}
+
+fn shift(now: DateTime<Utc>, delta: TimeDelta, past: bool) -> Option<DateTime<Utc>> {
+    if past {
+        now.checked_sub_signed(delta)
+    } else {
+        now.checked_add_signed(delta)
+    }
+}
+
+/// Matches times from `from` (inclusive) until `until` (exclusive).
+#[derive(Debug, Clone, Copy)]
+struct Range {
+    from: Option<Moment>,
+    until: Option<Moment>,
+}
+
+impl Range {
+    /// Builds the range for `field:<op><value>`. Durations count back from
+    /// now for `created` and `updated`, so `updated:<7d` means "in the last
+    /// week", and forward for `due`, so `due:<7d` means "within a week".
+    fn parse(op: Op, value: &str, past: bool) -> Result<Self, String> {
+        if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+            let next = date + Days::new(1);
+            let (from, until) = match op {
+                Op::Eq => (Some(date), Some(next)),
+                Op::Lt => (None, Some(date)),
+                Op::Le => (None, Some(next)),
+                Op::Gt => (Some(next), None),
+                Op::Ge => (Some(date), None),
+            };
+            return Ok(Self {
+                from: from.map(Moment::Date),
+                until: until.map(Moment::Date),
+            });
+        }
+
+        let split = value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len());
+        let (amount, unit) = value.split_at(split);
+        let amount: u32 = amount
+            .parse()
+            .map_err(|_| format!("expected a date or a duration like 7d, got '{}'", value))?;
+        let unit = match unit {
+            "h" => Unit::Hour,
+            "d" => Unit::Day,
+            "w" => Unit::Week,
+            "mo" => Unit::Month,
+            "y" => Unit::Year,
+            _ => {
+                return Err(format!(
+                    "unknown unit '{}' (expected h, d, w, mo or y)",
+                    unit
+                ));
+            }
+        };
+        // Years are applied as months, which must still fit a u32.
+        if matches!(unit, Unit::Year) && amount.checked_mul(12).is_none() {
+            return Err(format!("'{}' is too far away", value));
+        }
+        let moment = Some(Moment::Offset { amount, unit, past });
+
+        // "Less than a week" is after a week ago, but before a week from now.
+        let within = matches!(op, Op::Eq | Op::Lt | Op::Le);
+        Ok(if within == past {
+            Self {
+                from: moment,
+                until: None,
+            }
+        } else {
+            Self {
+                from: None,
+                until: moment,
+            }
+        })
+    }
+
+    fn contains(&self, time: DateTime<Utc>) -> bool {
+        self.from.is_none_or(|from| time >= from.resolve())
+            && self.until.is_none_or(|until| time < until.resolve())
+    }
+
+    fn contains_date(&self, date: NaiveDate) -> bool {
+        self.from.is_none_or(|from| date >= from.resolve_date())
+            && self.until.is_none_or(|until| date < until.resolve_date())
+    }
+
+    /// `IssueFilter`s on `field` for the range, or for everything outside it.
+    fn to_graphql(self, field: &str, negated: bool, timeless: bool) -> Value {
+        let bound = |moment: Moment| moment.to_graphql(timeless);
+
+        if !negated {
+            let mut comparator = serde_json::Map::new();
+            if let Some(from) = self.from {
+                comparator.insert("gte".into(), bound(from).into());
+            }
+            if let Some(until) = self.until {
+                comparator.insert("lt".into(), bound(until).into());
+            }
+            return json!({ field: comparator });
+        }
+
+        let mut outside: Vec<Value> = Vec::new();
+        if let Some(from) = self.from {
+            outside.push(json!({ field: { "lt": bound(from) } }));
+        }
+        if let Some(until) = self.until {
+            outside.push(json!({ field: { "gte": bound(until) } }));
+        }
+        if timeless {
+            outside.push(json!({ field: { "null": true } }));
+        }
+        json!({ "or": outside })
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(query: &str) -> Result<Self, Self::Err> {
+        let terms = tokenize(query)?
+            .into_iter()
+            .map(|token| {
+                let predicate = match token.field {
+                    Some(field) => parse_field(&field, &token.value)?,
+                    None => Predicate::Text(token.value.to_lowercase()),
+                };
+                Ok(Term {
+                    negated: token.negated,
+                    predicate,
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Self { terms })
+    }
+}
+
+/// How an issue matched the free-text terms of a filter.
+#[derive(Debug, Clone, Default)]
+pub struct TextMatch {
+    /// Sum of the fuzzy scores; higher is better.
+    pub score: i64,
+    /// Character indices to highlight in the identifier.
+    pub identifier: Vec<usize>,
+    /// Character indices to highlight in the title.
+    pub title: Vec<usize>,
+}
+
+impl Filter {
+    /// Whether results should be ranked by how well their text matched.
+    pub fn is_ranked(&self) -> bool {
+        self.terms
+            .iter()
+            .any(|term| !term.negated && matches!(term.predicate, Predicate::Text(_)))
+    }
+
+    /// The free-text terms, for a full-text search on the server.
+    pub fn search_text(&self) -> Option<String> {
+        let words: Vec<&str> = self
+            .terms
+            .iter()
+            .filter(|term| !term.negated)
+            .filter_map(|term| match &term.predicate {
+                Predicate::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+        Some(words.join(" ")).filter(|text| !text.is_empty())
+    }
+
+    /// Whether `issue` matches every term except the fuzzy free text.
+    /// `viewer` resolves `me`; without it, `assignee:me` matches nothing.
+    pub fn matches_fields(&self, issue: &Issue, viewer: Option<&User>) -> bool {
+        self.terms.iter().all(|term| match &term.predicate {
+            Predicate::Text(_) if !term.negated => true,
+            predicate => predicate.matches(issue, viewer) != term.negated,
+        })
+    }
+
+    /// Matches `issue` against every term, returning how its text matched.
+    pub fn matches(&self, issue: &Issue, viewer: Option<&User>) -> Option<TextMatch> {
+        if !self.matches_fields(issue, viewer) {
+            return None;
+        }
+
+        let mut text_match = TextMatch::default();
+        for term in self.terms.iter().filter(|term| !term.negated) {
+            let Predicate::Text(text) = &term.predicate else {
+                continue;
+            };
+            let identifier = fuzzy::find(text, &issue.identifier);
+            let title = fuzzy::find(text, &issue.title);
+            // Highlight whichever of the two matched better.
+            match (identifier, title) {
+                (Some(id), title) if title.as_ref().is_none_or(|t| id.score >= t.score) => {
+                    text_match.score += id.score;
+                    text_match.identifier.extend(id.positions);
+                }
+                (_, Some(title)) => {
+                    text_match.score += title.score;
+                    text_match.title.extend(title.positions);
+                }
+                (_, None) => return None,
+            }
+        }
+        Some(text_match)
+    }
+
+    /// The filter as Linear's `IssueFilter` input, or `None` to match every issue.
+    pub fn to_issue_filter(&self) -> Option<Value> {
+        // Linear can't match fuzzily, so text is only narrowed down locally.
+        let mut filters: Vec<Value> = self
+            .terms
+            .iter()
+            .filter(|term| term.negated || !matches!(term.predicate, Predicate::Text(_)))
+            .map(|term| term.predicate.to_graphql(term.negated))
+            .collect();
+
+        match filters.len() {
+            0 => None,
+            1 => filters.pop(),
+            _ => Some(json!({ "and": filters })),
+        }
+    }
+}
+
+impl Predicate {
+    fn matches(&self, issue: &Issue, viewer: Option<&User>) -> bool {
+        let contains = |haystack: &str, needle: &str| haystack.to_lowercase().contains(needle);
+
+        match self {
+            Predicate::Text(text) => {
+                contains(&issue.title, text)
+                    || contains(&issue.identifier, text)
+                    || issue
+                        .description
+                        .as_deref()
+                        .is_some_and(|description| contains(description, text))
+            }
+            Predicate::State(name) => issue.state.name.to_lowercase() == *name,
+            Predicate::Assignee(person) => person.matches(issue.assignee.as_ref(), viewer),
+            Predicate::Creator(person) => person.matches(issue.creator.as_ref(), viewer),
+            // Higher priorities sort first, so compare the other way round.
+            Predicate::Priority(op, priority) => op.accepts(priority.cmp(&issue.priority)),
+            Predicate::Label(name) => issue
+                .labels
+                .iter()
+                .any(|label| label.name.to_lowercase() == *name),
+            Predicate::Team(team) => {
+                issue.team.key.to_lowercase() == *team || issue.team.name.to_lowercase() == *team
+            }
+            Predicate::Project(name) => match (&issue.project, name) {
+                (Some(project), Some(name)) => contains(&project.name, name),
+                (project, name) => project.is_none() && name.is_none(),
+            },
+            Predicate::Created(range) => range.contains(issue.created_at),
+            Predicate::Updated(range) => range.contains(issue.updated_at),
+            Predicate::Due(range) => issue.due_date.is_some_and(|due| range.contains_date(due)),
+        }
+    }
+
+    /// Linear has no `not`, so negated terms use the opposite comparator.
+    fn to_graphql(&self, negated: bool) -> Value {
+        let (eq, contains) = if negated {
+            ("neqIgnoreCase", "notContainsIgnoreCase")
+        } else {
+            ("eqIgnoreCase", "containsIgnoreCase")
+        };
+
+        match self {
+            // Only negated text reaches the server; see `Filter::to_issue_filter`.
+            Predicate::Text(text) => json!({ "and": [
+                { "title": { contains: text } },
+                { "or": [
+                    { "description": { "null": true } },
+                    { "description": { contains: text } },
+                ] },
+            ] }),
+            Predicate::State(name) => json!({ "state": { "name": { eq: name } } }),
+            Predicate::Assignee(person) => person.to_graphql("assignee", negated),
+            Predicate::Creator(person) => person.to_graphql("creator", negated),
+            Predicate::Priority(op, priority) => {
+                let matching: Vec<u8> = Priority::ALL
+                    .into_iter()
+                    .filter(|&p| op.accepts(priority.cmp(&p)))
+                    .map(u8::from)
+                    .collect();
+                let comparator = if negated { "nin" } else { "in" };
+                json!({ "priority": { comparator: matching } })
+            }
+            Predicate::Label(name) if negated => {
+                json!({ "labels": { "every": { "name": { eq: name } } } })
+            }
+            Predicate::Label(name) => json!({ "labels": { "some": { "name": { eq: name } } } }),
+            Predicate::Team(team) => {
+                let either = if negated { "and" } else { "or" };
+                json!({ "team": { either: [
+                    { "key": { eq: team } },
+                    { "name": { eq: team } },
+                ] } })
+            }
+            Predicate::Project(None) => json!({ "project": { "null": !negated } }),
+            Predicate::Project(Some(name)) if negated => json!({ "or": [
+                { "project": { "null": true } },
+                { "project": { "name": { contains: name } } },
+            ] }),
+            Predicate::Project(Some(name)) => {
+                json!({ "project": { "name": { contains: name } } })
+            }
+            Predicate::Created(range) => range.to_graphql("createdAt", negated, false),
+            Predicate::Updated(range) => range.to_graphql("updatedAt", negated, false),
+            Predicate::Due(range) => range.to_graphql("dueDate", negated, true),
+        }
+    }
+}
+
+impl Person {
+    fn parse(value: &str) -> Self {
+        match value {
+            "me" => Person::Me,
+            "none" | "nobody" => Person::Nobody,
+            name => Person::Named(name.to_string()),
+        }
+    }
+
+    fn matches(&self, user: Option<&User>, viewer: Option<&User>) -> bool {
+        match (self, user) {
+            (Person::Nobody, user) => user.is_none(),
+            (Person::Me, Some(user)) => viewer.is_some_and(|viewer| viewer.id == user.id),
+            (Person::Named(name), Some(user)) => {
+                user.name.to_lowercase().contains(name) || user.email.to_lowercase().contains(name)
+            }
+            (_, None) => false,
+        }
+    }
+
+    fn to_graphql(&self, field: &str, negated: bool) -> Value {
+        match self {
+            Person::Nobody => json!({ field: { "null": !negated } }),
+            Person::Me if negated => json!({ "or": [
+                { field: { "null": true } },
+                { field: { "isMe": { "eq": false } } },
+            ] }),
+            Person::Me => json!({ field: { "isMe": { "eq": true } } }),
+            Person::Named(name) if negated => json!({ "or": [
+                { field: { "null": true } },
+                { field: { "and": [
+                    { "name": { "notContainsIgnoreCase": name } },
+                    { "email": { "notContainsIgnoreCase": name } },
+                ] } },
+            ] }),
+            Person::Named(name) => json!({ field: { "or": [
+                { "name": { "containsIgnoreCase": name } },
+                { "email": { "containsIgnoreCase": name } },
+            ] } }),
+        }
+    }
+}
+
+fn parse_field(field: &str, value: &str) -> Result<Predicate, String> {
+    if value.is_empty() {
+        return Err(format!("missing value after '{}:'", field));
+    }
+    let field = field.to_lowercase();
+    let text = value.to_lowercase();
+
+    Ok(match field.as_str() {
+        "state" => Predicate::State(text),
+        "assignee" => Predicate::Assignee(Person::parse(&text)),
+        "creator" => Predicate::Creator(Person::parse(&text)),
+        "priority" => {
+            let (op, priority) = Op::split(value);
+            Predicate::Priority(op, priority.parse()?)
+        }
+        "label" => Predicate::Label(text),
+        "team" => Predicate::Team(text),
+        "project" => Predicate::Project(Some(text).filter(|name| name != "none")),
+        "created" | "updated" | "due" => {
+            let (op, value) = Op::split(value);
+            let range = Range::parse(op, value, field != "due")?;
+            match field.as_str() {
+                "created" => Predicate::Created(range),
+                "updated" => Predicate::Updated(range),
+                _ => Predicate::Due(range),
+            }
+        }
+        _ => {
+            return Err(format!(
+                "unknown field '{}' (expected state, assignee, creator, priority, label, \
+                 team, project, created, updated or due)",
+                field
+            ));
+        }
+    })
+}
+
+struct Token {
+    negated: bool,
+    field: Option<String>,
+    value: String,
+}
+
+/// Splits the query on whitespace outside double quotes. The first unquoted
+/// `:` of a token ends its field name.
+fn tokenize(query: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = query.chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        if chars.peek().is_none() {
+            return Ok(tokens);
+        }
+
+        let mut token = Token {
+            negated: false,
+            field: None,
+            value: String::new(),
+        };
+        if chars.peek() == Some(&'-') {
+            chars.next();
+            match chars.peek() {
+                Some(c) if !c.is_whitespace() => token.negated = true,
+                _ => token.value.push('-'),
+            }
+        }
+
+        let mut quoted = false;
+        while let Some(c) = chars.next_if(|&c| quoted || !c.is_whitespace()) {
+            match c {
+                '"' => quoted = !quoted,
+                ':' if !quoted && token.field.is_none() && !token.value.is_empty() => {
+                    token.field = Some(std::mem::take(&mut token.value));
+                }
+                c => token.value.push(c),
+            }
+        }
+        if quoted {
+            return Err("unterminated quote".to_string());
+        }
+        tokens.push(token);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue_filter(query: &str) -> Value {
+        query.parse::<Filter>().unwrap().to_issue_filter().unwrap()
+    }
+
+    #[test]
+    fn field_names_ignore_case() {
+        assert_eq!(
+            issue_filter("Updated:<7d"),
+            json!({ "updatedAt": { "gte": "-P7D" } })
+        );
+        assert_eq!(
+            issue_filter("DUE:<7d"),
+            json!({ "dueDate": { "lt": "P7D" } })
+        );
+        assert_eq!(
+            issue_filter("State:Todo"),
+            json!({ "state": { "name": { "eqIgnoreCase": "todo" } } })
+        );
+    }
+
+    #[test]
+    fn created_and_updated_count_back_from_now() {
+        assert_eq!(
+            issue_filter("created:<2w"),
+            json!({ "createdAt": { "gte": "-P2W" } })
+        );
+        assert_eq!(
+            issue_filter("updated:>3mo"),
+            json!({ "updatedAt": { "lt": "-P3M" } })
+        );
+
+        let range = Range::parse(Op::Lt, "7d", true).unwrap();
+        assert!(range.contains(Utc::now() - TimeDelta::days(1)));
+        assert!(!range.contains(Utc::now() - TimeDelta::days(8)));
+    }
+
+    #[test]
+    fn due_counts_forward_from_now() {
+        assert_eq!(
+            issue_filter("due:>=1y"),
+            json!({ "dueDate": { "gte": "P1Y" } })
+        );
+
+        let today = Local::now().date_naive();
+        let range = Range::parse(Op::Lt, "7d", false).unwrap();
+        assert!(range.contains_date(today + Days::new(1)));
+        assert!(!range.contains_date(today + Days::new(8)));
+    }
+
+    #[test]
+    fn dates_cover_the_whole_day() {
+        assert_eq!(
+            issue_filter("due:2024-02-29"),
+            json!({ "dueDate": { "gte": "2024-02-29", "lt": "2024-03-01" } })
+        );
+        assert_eq!(
+            issue_filter("-due:<=2024-02-29"),
+            json!({ "or": [
+                { "dueDate": { "gte": "2024-03-01" } },
+                { "dueDate": { "null": true } },
+            ] })
+        );
+    }
+
+    #[test]
+    fn priority_comparisons() {
+        assert_eq!(
+            issue_filter("priority:>=high"),
+            json!({ "priority": { "in": [1, 2] } })
+        );
+        assert_eq!(
+            issue_filter("priority:<medium"),
+            json!({ "priority": { "in": [0, 4] } })
+        );
+        assert_eq!(
+            issue_filter("-priority:urgent"),
+            json!({ "priority": { "nin": [1] } })
+        );
+    }
+
+    #[test]
+    fn huge_offsets_are_rejected() {
+        assert!("created:<357913941y".parse::<Filter>().is_ok());
+        assert!("created:<357913942y".parse::<Filter>().is_err());
+        assert!("due:<99999999999d".parse::<Filter>().is_err());
+    }
+
+    #[test]
+    fn free_text_stays_local() {
+        let filter: Filter = "Login bug label:ios".parse().unwrap();
+        assert!(filter.is_ranked());
+        assert_eq!(filter.search_text().as_deref(), Some("login bug"));
+        assert_eq!(
+            filter.to_issue_filter(),
+            Some(json!({ "labels": { "some": { "name": { "eqIgnoreCase": "ios" } } } }))
+        );
+    }
+
+    #[test]
+    fn quotes_and_negation() {
+        assert_eq!(
+            issue_filter("-state:\"In Progress\""),
+            json!({ "state": { "name": { "neqIgnoreCase": "in progress" } } })
+        );
+        assert!("state:\"In Progress".parse::<Filter>().is_err());
+        assert!("status:done".parse::<Filter>().is_err());
+        assert!("label:".parse::<Filter>().is_err());
+    }
+}
@@ -0,0 +1,235 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::api::types::{NewIssue, Priority, Team};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Team,
+    Title,
+    Description,
+    Priority,
+    Assignee,
+    State,
+}
+
+pub const FIELDS: [Field; 6] = [
+    Field::Team,
+    Field::Title,
+    Field::Description,
+    Field::Priority,
+    Field::Assignee,
+    Field::State,
+];
+
+pub enum FormAction {
+    None,
+    Cancel,
+    Submit,
+}
+
+/// State of the "new issue" modal. Choice fields hold an index into the
+/// selected team's data, where 0 means "leave it to Linear's defaults".
+pub struct IssueForm {
+    pub focus: usize,
+    pub team: usize,
+    pub title: String,
+    pub description: String,
+    pub priority: Priority,
+    pub assignee: usize,
+    pub state: usize,
+    pub submitting: bool,
+    pub error: Option<String>,
+}
+
+impl IssueForm {
+    pub fn new() -> Self {
+        Self {
+            focus: 0,
+            team: 0,
+            title: String::new(),
+            description: String::new(),
+            priority: Priority::None,
+            assignee: 0,
+            state: 0,
+            submitting: false,
+            error: None,
+        }
+    }
+
+    pub fn focused(&self) -> Field {
+        FIELDS[self.focus]
+    }
+
+    pub fn on_key(&mut self, key: KeyCode, teams: &[Team]) -> FormAction {
+        if self.submitting {
+            return FormAction::None;
+        }
+
+        match key {
+            KeyCode::Esc => return FormAction::Cancel,
+            KeyCode::Enter => return FormAction::Submit,
+            KeyCode::Tab | KeyCode::Down => self.focus = (self.focus + 1) % FIELDS.len(),
+            KeyCode::BackTab | KeyCode::Up => {
+                self.focus = (self.focus + FIELDS.len() - 1) % FIELDS.len()
+            }
+            KeyCode::Left => self.cycle(teams, false),
+            KeyCode::Right => self.cycle(teams, true),
+            KeyCode::Backspace => {
+                if let Some(text) = self.focused_text() {
+                    text.pop();
+                }
+            }
+            KeyCode::Char(c) => match self.focused_text() {
+                Some(text) => text.push(c),
+                None if c == ' ' => self.cycle(teams, true),
+                None => {}
+            },
+            _ => {}
+        }
+
+        FormAction::None
+    }
+
+    fn focused_text(&mut self) -> Option<&mut String> {
+        match self.focused() {
+            Field::Title => Some(&mut self.title),
+            Field::Description => Some(&mut self.description),
+            _ => None,
+        }
+    }
+
+    fn cycle(&mut self, teams: &[Team], forward: bool) {
+        let step = |value: usize, len: usize| {
+            if len == 0 {
+                0
+            } else if forward {
+                (value + 1) % len
+            } else {
+                (value + len - 1) % len
+            }
+        };
+
+        let team = teams.get(self.team);
+        match self.focused() {
+            Field::Team => {
+                self.team = step(self.team, teams.len());
+                self.assignee = 0;
+                self.state = 0;
+            }
+            Field::Priority => {
+                let index = Priority::ALL.iter().position(|&p| p == self.priority);
+                self.priority = Priority::ALL[step(index.unwrap_or(0), Priority::ALL.len())];
+            }
+            Field::Assignee => {
+                let len = team.map_or(0, |t| t.members.len()) + 1;
+                self.assignee = step(self.assignee, len);
+            }
+            Field::State => {
+                let len = team.map_or(0, |t| t.states.len()) + 1;
+                self.state = step(self.state, len);
+            }
+            Field::Title | Field::Description => {}
+        }
+    }
+
+    pub fn to_new_issue(&self, teams: &[Team]) -> Result<NewIssue, String> {
+        let team = teams.get(self.team).ok_or("No team selected")?;
+        let title = self.title.trim();
+        if title.is_empty() {
+            return Err("Title is required".to_string());
+        }
+
+        let description = self.description.trim();
+
+        Ok(NewIssue {
+            team_id: team.id.clone(),
+            title: title.to_string(),
+            description: (!description.is_empty()).then(|| description.to_string()),
+            priority: (self.priority != Priority::None).then_some(self.priority),
+            assignee_id: self
+                .assignee
+                .checked_sub(1)
+                .and_then(|i| team.members.get(i))
+                .map(|user| user.id.clone()),
+            state_id: self
+                .state
+                .checked_sub(1)
+                .and_then(|i| team.states.get(i))
+                .map(|state| state.id.clone()),
+        })
+    }
+}
+
+pub enum CommentTarget {
+    New { issue_id: String },
+    Edit { comment_id: String },
+}
+
+/// State of the popup used to write a new comment or edit an existing one.
+pub struct CommentEditor {
+    pub target: CommentTarget,
+    pub text: String,
+    pub submitting: bool,
+    pub error: Option<String>,
+}
+
+impl CommentEditor {
+    pub fn new(target: CommentTarget, text: String) -> Self {
+        Self {
+            target,
+            text,
+            submitting: false,
+            error: None,
+        }
+    }
+
+    /// Enter starts a new line; Ctrl+S, or Ctrl+Enter where the terminal
+    /// reports it, saves.
+    pub fn on_key(&mut self, key: KeyEvent) -> FormAction {
+        if self.submitting {
+            return FormAction::None;
+        }
+
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => return FormAction::Cancel,
+            KeyCode::Char('s') | KeyCode::Enter if ctrl => return FormAction::Submit,
+            KeyCode::Enter => self.text.push('\n'),
+            KeyCode::Backspace => {
+                self.text.pop();
+            }
+            KeyCode::Char(c) => self.text.push(c),
+            _ => {}
+        }
+
+        FormAction::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(editor: &mut CommentEditor, code: KeyCode, modifiers: KeyModifiers) -> FormAction {
+        editor.on_key(KeyEvent::new(code, modifiers))
+    }
+
+    #[test]
+    fn comment_editor_enter_starts_a_new_line() {
+        let target = CommentTarget::Edit {
+            comment_id: "1".to_string(),
+        };
+        let mut editor = CommentEditor::new(target, "first".to_string());
+
+        let action = press(&mut editor, KeyCode::Enter, KeyModifiers::NONE);
+        assert!(matches!(action, FormAction::None));
+        press(&mut editor, KeyCode::Char('x'), KeyModifiers::NONE);
+        assert_eq!(editor.text, "first\nx");
+
+        let action = press(&mut editor, KeyCode::Char('s'), KeyModifiers::CONTROL);
+        assert!(matches!(action, FormAction::Submit));
+        let action = press(&mut editor, KeyCode::Enter, KeyModifiers::CONTROL);
+        assert!(matches!(action, FormAction::Submit));
+        assert_eq!(editor.text, "first\nx");
+    }
+}
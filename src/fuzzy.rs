@@ -0,0 +1,171 @@
+//! fzf-style fuzzy matching: the pattern's characters must appear in order,
+//! and matches score higher the more of them are consecutive or start a word.
+
+const SCORE_MATCH: i64 = 16;
+const GAP_START: i64 = -3;
+const GAP_EXTENSION: i64 = -1;
+/// A match at the start of a word, e.g. the `f` of `fix` in `bug: fix it`.
+const BONUS_BOUNDARY: i64 = 8;
+/// A match on a lowercase-to-uppercase or letter-to-digit transition.
+const BONUS_CAMEL: i64 = 7;
+/// Consecutive matches earn at least this, so a gap always costs more than it saves.
+const BONUS_CONSECUTIVE: i64 = -(GAP_START + GAP_EXTENSION);
+/// The first pattern character's bonus counts double, as in fzf.
+const FIRST_CHAR_MULTIPLIER: i64 = 2;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Match {
+    pub score: i64,
+    /// Character (not byte) indices of the matched characters in the text.
+    pub positions: Vec<usize>,
+}
+
+/// Finds the best-scoring alignment of `pattern` in `text`, ignoring case.
+/// `pattern` must already be lowercase.
+pub fn find(pattern: &str, text: &str) -> Option<Match> {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = text.iter().map(|&c| lowercase(c)).collect();
+    if pattern.is_empty() || !is_subsequence(&pattern, &lower) {
+        return None;
+    }
+
+    let bonuses: Vec<i64> = (0..text.len())
+        .map(|j| bonus(j.checked_sub(1).map(|i| text[i]), text[j]))
+        .collect();
+    let (m, n) = (pattern.len(), text.len());
+    // scores[i][j]: best score for pattern[..=i] with pattern[i] matched at text[j].
+    // from[i][j]: where pattern[i - 1] was matched on that best path.
+    let mut scores = vec![vec![None::<i64>; n]; m];
+    let mut from = vec![vec![0usize; n]; m];
+
+    for j in 0..n {
+        if lower[j] == pattern[0] {
+            scores[0][j] = Some(SCORE_MATCH + bonuses[j] * FIRST_CHAR_MULTIPLIER);
+        }
+    }
+    for i in 1..m {
+        // Best path through pattern[i - 1] at some k < j - 1, less the gap up to j.
+        let mut gapped: Option<(i64, usize)> = None;
+
+        for j in i..n {
+            if j >= 2 {
+                let opened = scores[i - 1][j - 2].map(|score| (score + GAP_START, j - 2));
+                gapped = match (gapped.map(|(s, k)| (s + GAP_EXTENSION, k)), opened) {
+                    (Some(a), Some(b)) => Some(if b.0 >= a.0 { b } else { a }),
+                    (a, b) => a.or(b),
+                };
+            }
+            if lower[j] != pattern[i] {
+                continue;
+            }
+
+            let consecutive = scores[i - 1][j - 1]
+                .map(|score| (score + bonuses[j].max(BONUS_CONSECUTIVE), j - 1));
+            let after_gap = gapped.map(|(score, k)| (score + bonuses[j], k));
+            let best = match (consecutive, after_gap) {
+                (Some(a), Some(b)) => Some(if a.0 >= b.0 { a } else { b }),
+                (a, b) => a.or(b),
+            };
+            if let Some((score, k)) = best {
+                scores[i][j] = Some(score + SCORE_MATCH);
+                from[i][j] = k;
+            }
+        }
+    }
+
+    // Prefer the earliest end on ties, like fzf.
+    let (mut j, score) = scores[m - 1]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, score)| score.map(|score| (j, score)))
+        .fold(None, |best: Option<(usize, i64)>, (j, score)| match best {
+            Some((_, best_score)) if best_score >= score => best,
+            _ => Some((j, score)),
+        })?;
+
+    let mut positions = vec![0; m];
+    for i in (0..m).rev() {
+        positions[i] = j;
+        j = from[i][j];
+    }
+    Some(Match { score, positions })
+}
+
+fn is_subsequence(pattern: &[char], text: &[char]) -> bool {
+    let mut text = text.iter();
+    pattern.iter().all(|p| text.any(|c| c == p))
+}
+
+fn lowercase(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+fn bonus(previous: Option<char>, current: char) -> i64 {
+    match previous {
+        None => BONUS_BOUNDARY,
+        Some(previous) if !previous.is_alphanumeric() && current.is_alphanumeric() => {
+            BONUS_BOUNDARY
+        }
+        Some(previous)
+            if (previous.is_lowercase() && current.is_uppercase())
+                || (previous.is_alphabetic() && current.is_numeric()) =>
+        {
+            BONUS_CAMEL
+        }
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn positions(pattern: &str, text: &str) -> Vec<usize> {
+        find(pattern, text).unwrap().positions
+    }
+
+    #[test]
+    fn requires_every_character_in_order() {
+        assert!(find("bug", "Fix the bug").is_some());
+        assert!(find("gub", "Fix the bug").is_none());
+        assert!(find("bugs", "Fix the bug").is_none());
+        assert!(find("", "Fix the bug").is_none());
+    }
+
+    #[test]
+    fn ignores_case() {
+        assert_eq!(positions("eng", "ENG-123"), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn positions_are_char_indices() {
+        assert_eq!(positions("café", "Ünïcödé CAFÉ"), vec![8, 9, 10, 11]);
+    }
+
+    #[test]
+    fn prefers_word_starts() {
+        // The `f` and `b` that start words, not the ones inside them.
+        assert_eq!(positions("fb", "offbeat fix: bug"), vec![8, 13]);
+        assert_eq!(positions("ls", "LoginScreen"), vec![0, 5]);
+    }
+
+    #[test]
+    fn consecutive_matches_score_higher() {
+        let together = find("login", "Fix login form").unwrap();
+        let apart = find("login", "Log out, sign in").unwrap();
+        assert!(together.score > apart.score);
+    }
+
+    #[test]
+    fn boundary_matches_score_higher() {
+        let boundary = find("api", "Fix api client").unwrap();
+        let inside = find("api", "Rapid fixes").unwrap();
+        assert!(boundary.score > inside.score);
+    }
+
+    #[test]
+    fn ties_prefer_the_earliest_match() {
+        assert_eq!(positions("ab", "ab ab"), vec![0, 1]);
+    }
+}
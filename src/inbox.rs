@@ -0,0 +1,127 @@
+use chrono::{DateTime, Datelike, Days, Local, NaiveDate, Utc};
+use crossterm::event::KeyCode;
+
+use crate::api::types::Notification;
+
+pub enum InboxAction {
+    None,
+    /// Show the issue with this id, marking the notification with this id read.
+    OpenIssue {
+        notification_id: String,
+        issue_id: String,
+    },
+    MarkRead(String),
+    MarkAllRead,
+    Archive(String),
+    Snooze(String),
+    Refresh,
+}
+
+/// State of the Inbox tab: the notifications that were unread when it loaded.
+/// Ones read since stay listed, dimmed, until the next refresh.
+pub struct InboxTab {
+    pub notifications: Vec<Notification>,
+    pub selected: usize,
+    pub loading: bool,
+}
+
+impl InboxTab {
+    pub fn new() -> Self {
+        Self {
+            notifications: Vec::new(),
+            selected: 0,
+            loading: false,
+        }
+    }
+
+    pub fn on_key(&mut self, key: KeyCode) -> InboxAction {
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') if self.selected + 1 < self.notifications.len() => {
+                self.selected += 1;
+            }
+            KeyCode::Char('r') => return InboxAction::Refresh,
+            KeyCode::Char('M') if self.unread() > 0 => return InboxAction::MarkAllRead,
+            _ => {}
+        }
+        let Some(notification) = self.notifications.get(self.selected) else {
+            return InboxAction::None;
+        };
+        let id = notification.id.clone();
+
+        match key {
+            KeyCode::Enter => match &notification.issue {
+                Some(issue) => InboxAction::OpenIssue {
+                    notification_id: id,
+                    issue_id: issue.id.clone(),
+                },
+                None => InboxAction::None,
+            },
+            KeyCode::Char('m') if notification.read_at.is_none() => InboxAction::MarkRead(id),
+            KeyCode::Char('a') => InboxAction::Archive(id),
+            KeyCode::Char('z') => InboxAction::Snooze(id),
+            _ => InboxAction::None,
+        }
+    }
+
+    pub fn set_notifications(&mut self, notifications: Vec<Notification>) {
+        self.notifications = notifications;
+        self.selected = self
+            .selected
+            .min(self.notifications.len().saturating_sub(1));
+    }
+
+    pub fn unread(&self) -> usize {
+        self.notifications
+            .iter()
+            .filter(|n| n.read_at.is_none())
+            .count()
+    }
+
+    pub fn mark_read(&mut self, id: &str) {
+        if let Some(notification) = self.notifications.iter_mut().find(|n| n.id == id) {
+            notification.read_at.get_or_insert_with(Utc::now);
+        }
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        self.notifications.retain(|n| n.id != id);
+        self.selected = self
+            .selected
+            .min(self.notifications.len().saturating_sub(1));
+    }
+}
+
+/// When a notification can be snoozed until: in an hour, tomorrow morning
+/// or next week's Monday morning.
+pub fn snooze_times() -> Vec<(&'static str, DateTime<Utc>)> {
+    let now = Local::now();
+    let today = now.date_naive();
+    let morning = |date: Option<NaiveDate>| {
+        date?
+            .and_hms_opt(9, 0, 0)?
+            .and_local_timezone(Local)
+            .earliest()
+            .map(|time| time.with_timezone(&Utc))
+    };
+    // On Sundays, a week from tomorrow rather than tomorrow again.
+    let days_to_monday = match 7 - u64::from(today.weekday().num_days_from_monday()) {
+        1 => 8,
+        days => days,
+    };
+
+    [
+        (
+            "In an hour",
+            Some(now.to_utc() + chrono::Duration::hours(1)),
+        ),
+        ("Tomorrow", morning(today.checked_add_days(Days::new(1)))),
+        (
+            "Next week",
+            morning(today.checked_add_days(Days::new(days_to_monday))),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(label, time)| Some((label, time?)))
+    .collect()
+}
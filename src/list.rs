@@ -0,0 +1,422 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::api::types::{Issue, Team};
+
+/// How far back the "Recently updated" scope goes, as an ISO 8601 duration before now.
+const RECENTLY_UPDATED: &str = "-P2W";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortMode {
+    Priority,
+    #[default]
+    Updated,
+    Created,
+    State,
+    Assignee,
+    Identifier,
+    DueDate,
+}
+
+impl SortMode {
+    const ALL: [SortMode; 7] = [
+        SortMode::Priority,
+        SortMode::Updated,
+        SortMode::Created,
+        SortMode::State,
+        SortMode::Assignee,
+        SortMode::Identifier,
+        SortMode::DueDate,
+    ];
+
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|&mode| mode == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SortMode::Priority => "priority",
+            SortMode::Updated => "updated",
+            SortMode::Created => "created",
+            SortMode::State => "state",
+            SortMode::Assignee => "assignee",
+            SortMode::Identifier => "identifier",
+            SortMode::DueDate => "due date",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupBy {
+    #[default]
+    None,
+    State,
+    Assignee,
+    Project,
+    Team,
+}
+
+impl GroupBy {
+    const ALL: [GroupBy; 5] = [
+        GroupBy::None,
+        GroupBy::State,
+        GroupBy::Assignee,
+        GroupBy::Project,
+        GroupBy::Team,
+    ];
+
+    pub fn next(self) -> Self {
+        let i = Self::ALL
+            .iter()
+            .position(|&group| group == self)
+            .unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GroupBy::None => "none",
+            GroupBy::State => "state",
+            GroupBy::Assignee => "assignee",
+            GroupBy::Project => "project",
+            GroupBy::Team => "team",
+        }
+    }
+}
+
+/// Built-in sets of issues the list can be limited to, relative to the viewer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Scope {
+    All,
+    #[default]
+    Assigned,
+    Created,
+    Subscribed,
+    Recent,
+}
+
+impl Scope {
+    pub const ALL: [Scope; 5] = [
+        Scope::All,
+        Scope::Assigned,
+        Scope::Created,
+        Scope::Subscribed,
+        Scope::Recent,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            Scope::All => "all",
+            Scope::Assigned => "assigned",
+            Scope::Created => "created",
+            Scope::Subscribed => "subscribed",
+            Scope::Recent => "recent",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Scope::All => "All issues",
+            Scope::Assigned => "Assigned to me",
+            Scope::Created => "Created by me",
+            Scope::Subscribed => "Subscribed",
+            Scope::Recent => "Recently updated",
+        }
+    }
+
+    /// The scope as a Linear `IssueFilter`; `None` for every issue.
+    pub fn to_issue_filter(self) -> Option<Value> {
+        let me = json!({ "isMe": { "eq": true } });
+        match self {
+            Scope::All => None,
+            Scope::Assigned => Some(json!({ "assignee": me })),
+            Scope::Created => Some(json!({ "creator": me })),
+            Scope::Subscribed => Some(json!({ "subscribers": { "some": me } })),
+            Scope::Recent => Some(json!({ "updatedAt": { "gt": RECENTLY_UPDATED } })),
+        }
+    }
+}
+
+impl FromStr for Scope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|scope| scope.key() == s.to_ascii_lowercase())
+            .ok_or_else(|| {
+                format!(
+                    "unknown scope '{}' (expected all, assigned, created, subscribed or recent)",
+                    s
+                )
+            })
+    }
+}
+
+/// One line of the issue list: either a group header or an index into `App::issues`.
+#[derive(Debug, Clone)]
+pub enum Row {
+    Group {
+        key: String,
+        label: String,
+        /// Hex colour of the group, for state groups.
+        color: Option<String>,
+        count: usize,
+        collapsed: bool,
+    },
+    Issue(usize),
+}
+
+/// Workflow-state order of every known state id, so issues sort the way
+/// Linear's board lays them out rather than alphabetically.
+pub fn state_order(teams: &[Team]) -> HashMap<&str, (u8, f64)> {
+    teams
+        .iter()
+        .flat_map(|team| &team.states)
+        .map(|state| (state.id.as_str(), state.sort_key()))
+        .collect()
+}
+
+/// Sorts `indices` into `issues` in place. Newest first for timestamps;
+/// missing assignees and due dates go last.
+pub fn sort(
+    indices: &mut [usize],
+    issues: &[Issue],
+    mode: SortMode,
+    states: &HashMap<&str, (u8, f64)>,
+) {
+    indices.sort_by(|&a, &b| {
+        let (a, b) = (&issues[a], &issues[b]);
+        let ordering = match mode {
+            SortMode::Priority => a.priority.cmp(&b.priority),
+            SortMode::Updated => b.updated_at.cmp(&a.updated_at),
+            SortMode::Created => b.created_at.cmp(&a.created_at),
+            SortMode::State => compare_states(a, b, states),
+            SortMode::Assignee => compare_names(
+                a.assignee.as_ref().map(|u| u.name.as_str()),
+                b.assignee.as_ref().map(|u| u.name.as_str()),
+            ),
+            SortMode::Identifier => {
+                identifier_key(&a.identifier).cmp(&identifier_key(&b.identifier))
+            }
+            SortMode::DueDate => match (a.due_date, b.due_date) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+        };
+        ordering.then_with(|| b.updated_at.cmp(&a.updated_at))
+    });
+}
+
+/// Lays out the sorted issues under group headers. Issues of a collapsed
+/// group are left out, but still counted in its header.
+pub fn rows(
+    indices: &[usize],
+    issues: &[Issue],
+    group_by: GroupBy,
+    collapsed: &HashSet<String>,
+    states: &HashMap<&str, (u8, f64)>,
+) -> Vec<Row> {
+    if group_by == GroupBy::None {
+        return indices.iter().map(|&i| Row::Issue(i)).collect();
+    }
+
+    // Groups keep the order of the issue that sorts first within them...
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    for &i in indices {
+        let key = group_key(&issues[i], group_by);
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, members)) => members.push(i),
+            None => groups.push((key, vec![i])),
+        }
+    }
+    // ...then are ordered by the grouped field, with "none" groups last.
+    groups.sort_by(|(_, a), (_, b)| {
+        let (a, b) = (&issues[a[0]], &issues[b[0]]);
+        match group_by {
+            GroupBy::State => compare_states(a, b, states),
+            GroupBy::Assignee => compare_names(
+                a.assignee.as_ref().map(|u| u.name.as_str()),
+                b.assignee.as_ref().map(|u| u.name.as_str()),
+            ),
+            GroupBy::Project => compare_names(
+                a.project.as_ref().map(|p| p.name.as_str()),
+                b.project.as_ref().map(|p| p.name.as_str()),
+            ),
+            GroupBy::Team => a.team.key.cmp(&b.team.key),
+            GroupBy::None => Ordering::Equal,
+        }
+    });
+
+    let mut rows = Vec::new();
+    for (key, members) in groups {
+        let first = &issues[members[0]];
+        let (label, color) = match group_by {
+            GroupBy::State => (first.state.name.clone(), Some(first.state.color.clone())),
+            GroupBy::Assignee => (
+                first
+                    .assignee
+                    .as_ref()
+                    .map_or("Unassigned".to_string(), |u| u.name.clone()),
+                None,
+            ),
+            GroupBy::Project => (
+                first
+                    .project
+                    .as_ref()
+                    .map_or("No project".to_string(), |p| p.name.clone()),
+                None,
+            ),
+            GroupBy::Team => (format!("{} ({})", first.team.name, first.team.key), None),
+            GroupBy::None => unreachable!(),
+        };
+        let is_collapsed = collapsed.contains(&key);
+
+        rows.push(Row::Group {
+            key,
+            label,
+            color,
+            count: members.len(),
+            collapsed: is_collapsed,
+        });
+        if !is_collapsed {
+            rows.extend(members.into_iter().map(Row::Issue));
+        }
+    }
+    rows
+}
+
+/// A fixed set of issues laid out under workflow-state headers, most
+/// pressing first within each state, as shown for a project or a cycle.
+pub struct StateGroups {
+    pub issues: Vec<Issue>,
+    pub rows: Vec<Row>,
+    /// Index into `rows`.
+    pub selected: usize,
+    collapsed: HashSet<String>,
+}
+
+impl StateGroups {
+    pub fn new(issues: Vec<Issue>, teams: &[Team]) -> Self {
+        let mut groups = Self {
+            issues,
+            rows: Vec::new(),
+            selected: 0,
+            collapsed: HashSet::new(),
+        };
+        groups.arrange(teams);
+        groups
+    }
+
+    pub fn up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn down(&mut self) {
+        if self.selected + 1 < self.rows.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn selected_issue(&self) -> Option<&Issue> {
+        match self.rows.get(self.selected)? {
+            Row::Issue(i) => self.issues.get(*i),
+            Row::Group { .. } => None,
+        }
+    }
+
+    /// Folds or unfolds the selected group, if a header is selected.
+    pub fn toggle_selected_group(&mut self, teams: &[Team]) {
+        if let Some(Row::Group { key, .. }) = self.rows.get(self.selected) {
+            if !self.collapsed.remove(key) {
+                self.collapsed.insert(key.clone());
+            }
+            self.arrange(teams);
+        }
+    }
+
+    /// Replaces an issue with its updated version, adds it if it is new, or
+    /// drops it when `keep` is false because it no longer belongs here.
+    pub fn upsert(&mut self, issue: &Issue, keep: bool, teams: &[Team]) {
+        let existing = self.issues.iter().position(|i| i.id == issue.id);
+        match (existing, keep) {
+            (Some(i), true) => self.issues[i] = issue.clone(),
+            (Some(i), false) => {
+                self.issues.remove(i);
+            }
+            (None, true) => self.issues.push(issue.clone()),
+            (None, false) => return,
+        }
+        self.arrange(teams);
+    }
+
+    /// Rebuilds the rows, e.g. once the teams' workflow states are known.
+    pub fn arrange(&mut self, teams: &[Team]) {
+        let states = state_order(teams);
+        let mut indices: Vec<usize> = (0..self.issues.len()).collect();
+        sort(&mut indices, &self.issues, SortMode::Priority, &states);
+        self.rows = rows(
+            &indices,
+            &self.issues,
+            GroupBy::State,
+            &self.collapsed,
+            &states,
+        );
+        self.selected = self.selected.min(self.rows.len().saturating_sub(1));
+    }
+}
+
+fn group_key(issue: &Issue, group_by: GroupBy) -> String {
+    match group_by {
+        GroupBy::None => String::new(),
+        GroupBy::State => issue.state.id.clone(),
+        GroupBy::Assignee => issue
+            .assignee
+            .as_ref()
+            .map(|u| u.id.clone())
+            .unwrap_or_default(),
+        GroupBy::Project => issue
+            .project
+            .as_ref()
+            .map(|p| p.id.clone())
+            .unwrap_or_default(),
+        GroupBy::Team => issue.team.id.clone(),
+    }
+}
+
+fn compare_states(a: &Issue, b: &Issue, states: &HashMap<&str, (u8, f64)>) -> Ordering {
+    match (
+        states.get(a.state.id.as_str()),
+        states.get(b.state.id.as_str()),
+    ) {
+        (Some(a), Some(b)) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+        _ => a.state.name.cmp(&b.state.name),
+    }
+}
+
+/// Case-insensitive, with `None` after every name.
+fn compare_names(a: Option<&str>, b: Option<&str>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Splits `ENG-123` into `("ENG", 123)` so that ENG-9 sorts before ENG-10.
+fn identifier_key(identifier: &str) -> (&str, u64) {
+    match identifier.rsplit_once('-') {
+        Some((team, number)) => (team, number.parse().unwrap_or(0)),
+        None => (identifier, 0),
+    }
+}
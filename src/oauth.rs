@@ -1,6 +1,6 @@
 use anyhow::Result;
 use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
-use rand::{RngCore, thread_rng};
+use rand::{RngCore, rng};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::{
@@ -63,7 +63,7 @@ pub fn authenticate() -> Result<OAuthToken> {
 
 fn generate_code_verifier() -> String {
     let mut verifier = vec![0u8; 64];
-    thread_rng().fill_bytes(&mut verifier);
+    rng().fill_bytes(&mut verifier);
     URL_SAFE_NO_PAD.encode(&verifier)
 }
 
@@ -76,7 +76,7 @@ fn generate_code_challenge(verifier: &str) -> String {
 
 fn generate_state() -> String {
     let mut state = vec![0u8; 16];
-    thread_rng().fill_bytes(&mut state);
+    rng().fill_bytes(&mut state);
     URL_SAFE_NO_PAD.encode(&state)
 }
 
@@ -100,27 +100,27 @@ fn run_callback_server(tx: mpsc::Sender<Result<String>>, expected_state: &str) -
     for stream in listener.incoming() {
         let mut stream = stream?;
         let mut buffer = [0; 2048];
-        stream.read(&mut buffer)?;
-
-        let request = String::from_utf8_lossy(&buffer);
-
-        if let Some(line) = request.lines().next() {
-            if line.starts_with("GET /callback") {
-                let url = format!("http://localhost{}", &line[4..line.len() - 9]);
-                let parsed = Url::parse(&url)?;
-                let params: HashMap<_, _> = parsed.query_pairs().collect();
-
-                if let (Some(code), Some(state)) = (params.get("code"), params.get("state")) {
-                    if state == expected_state {
-                        stream.write_all(b"HTTP/1.1 200 OK\r\n\r\n<html><body>Success! You can close this window.</body></html>")?;
-                        tx.send(Ok(code.to_string()))?;
-                        return Ok(());
-                    } else {
-                        tx.send(Err(anyhow::anyhow!("Invalid state parameter")))?;
-                    }
-                } else if let Some(error) = params.get("error") {
-                    tx.send(Err(anyhow::anyhow!("OAuth error: {}", error)))?;
+        let read = stream.read(&mut buffer)?;
+
+        let request = String::from_utf8_lossy(&buffer[..read]);
+
+        if let Some(line) = request.lines().next()
+            && line.starts_with("GET /callback")
+        {
+            let url = format!("http://localhost{}", &line[4..line.len() - 9]);
+            let parsed = Url::parse(&url)?;
+            let params: HashMap<_, _> = parsed.query_pairs().collect();
+
+            if let (Some(code), Some(state)) = (params.get("code"), params.get("state")) {
+                if state == expected_state {
+                    stream.write_all(b"HTTP/1.1 200 OK\r\n\r\n<html><body>Success! You can close this window.</body></html>")?;
+                    tx.send(Ok(code.to_string()))?;
+                    return Ok(());
+                } else {
+                    tx.send(Err(anyhow::anyhow!("Invalid state parameter")))?;
                 }
+            } else if let Some(error) = params.get("error") {
+                tx.send(Err(anyhow::anyhow!("OAuth error: {}", error)))?;
             }
         }
     }
@@ -0,0 +1,89 @@
+use crossterm::event::KeyCode;
+
+/// What to do with the chosen item once a picker is confirmed.
+pub enum PickerAction {
+    SetState {
+        issue_id: String,
+    },
+    SetPriority {
+        issue_id: String,
+    },
+    /// Moves the issue into the chosen cycle; the empty id takes it out of its cycle.
+    SetCycle {
+        issue_id: String,
+    },
+    /// Limits the issue list to the chosen team.
+    SetTeam,
+    /// Limits the issue list to the chosen built-in scope, e.g. assigned to me.
+    SetScope,
+    /// Shows or hides the chosen list column; the picker stays open.
+    ToggleColumn,
+    /// Switches the issue list to the chosen saved or Linear view.
+    OpenView,
+    /// Snoozes the notification until the chosen time, an RFC 3339 timestamp.
+    Snooze {
+        notification_id: String,
+    },
+}
+
+pub struct PickerItem {
+    pub id: String,
+    pub label: String,
+    /// Hex colour from the API, e.g. `#f2c94c`.
+    pub color: Option<String>,
+}
+
+pub enum PickerEvent {
+    None,
+    Cancel,
+    Confirm,
+}
+
+/// A popup list of choices applied to the current selection.
+pub struct Picker {
+    pub title: String,
+    pub items: Vec<PickerItem>,
+    pub selected: usize,
+    pub action: PickerAction,
+}
+
+impl Picker {
+    pub fn new(title: impl Into<String>, items: Vec<PickerItem>, action: PickerAction) -> Self {
+        Self {
+            title: title.into(),
+            items,
+            selected: 0,
+            action,
+        }
+    }
+
+    /// Starts with the cursor on the item with the given id, if present.
+    pub fn with_selected(mut self, id: &str) -> Self {
+        self.selected = self
+            .items
+            .iter()
+            .position(|item| item.id == id)
+            .unwrap_or(0);
+        self
+    }
+
+    pub fn selected_item(&self) -> Option<&PickerItem> {
+        self.items.get(self.selected)
+    }
+
+    pub fn on_key(&mut self, key: KeyCode) -> PickerEvent {
+        match key {
+            KeyCode::Esc | KeyCode::Char('q') => return PickerEvent::Cancel,
+            KeyCode::Enter => return PickerEvent::Confirm,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.selected + 1 < self.items.len() => {
+                self.selected += 1;
+            }
+            _ => {}
+        }
+
+        PickerEvent::None
+    }
+}
@@ -0,0 +1,132 @@
+use crossterm::event::KeyCode;
+
+use crate::api::types::{Issue, Project, ProjectDetail, Team};
+use crate::list::{Row, StateGroups};
+
+pub enum ProjectsAction {
+    None,
+    /// Load the project with this id and show it.
+    Open(String),
+    /// Show the issue with this id.
+    OpenIssue(String),
+    Refresh,
+}
+
+/// State of the Projects tab: the list of projects, or one project's issues
+/// grouped by status next to its latest updates.
+pub struct ProjectsTab {
+    pub projects: Vec<Project>,
+    pub selected: usize,
+    pub loading: bool,
+    /// The open project; its issues are moved out into `issues`.
+    pub detail: Option<ProjectDetail>,
+    pub issues: Option<StateGroups>,
+    /// Id of the project being loaded.
+    pub pending: Option<String>,
+}
+
+impl ProjectsTab {
+    pub fn new() -> Self {
+        Self {
+            projects: Vec::new(),
+            selected: 0,
+            loading: false,
+            detail: None,
+            issues: None,
+            pending: None,
+        }
+    }
+
+    /// Whether a project is open (or opening) rather than the list shown.
+    pub fn is_open(&self) -> bool {
+        self.detail.is_some() || self.pending.is_some()
+    }
+
+    pub fn on_key(&mut self, key: KeyCode, teams: &[Team]) -> ProjectsAction {
+        let (Some(detail), Some(issues)) = (&self.detail, &mut self.issues) else {
+            match key {
+                KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') if self.selected + 1 < self.projects.len() => {
+                    self.selected += 1;
+                }
+                KeyCode::Enter if self.pending.is_none() => {
+                    if let Some(project) = self.projects.get(self.selected) {
+                        return ProjectsAction::Open(project.id.clone());
+                    }
+                }
+                KeyCode::Esc | KeyCode::Backspace => self.close(),
+                KeyCode::Char('r') => return ProjectsAction::Refresh,
+                _ => {}
+            }
+            return ProjectsAction::None;
+        };
+
+        match key {
+            KeyCode::Esc | KeyCode::Backspace => self.close(),
+            KeyCode::Up | KeyCode::Char('k') => issues.up(),
+            KeyCode::Down | KeyCode::Char('j') => issues.down(),
+            KeyCode::Enter | KeyCode::Char(' ') => match issues.rows.get(issues.selected) {
+                Some(Row::Group { .. }) => issues.toggle_selected_group(teams),
+                Some(Row::Issue(_)) => {
+                    if let Some(issue) = issues.selected_issue() {
+                        return ProjectsAction::OpenIssue(issue.id.clone());
+                    }
+                }
+                None => {}
+            },
+            KeyCode::Char('r') => return ProjectsAction::Open(detail.project.id.clone()),
+            _ => {}
+        }
+
+        ProjectsAction::None
+    }
+
+    pub fn set_projects(&mut self, projects: Vec<Project>) {
+        self.projects = projects;
+        self.selected = self.selected.min(self.projects.len().saturating_sub(1));
+    }
+
+    pub fn set_detail(&mut self, mut detail: ProjectDetail, teams: &[Team]) {
+        let issues = std::mem::take(&mut detail.issues);
+        match &mut self.issues {
+            // Refreshed: keep the cursor and folded groups.
+            Some(groups)
+                if self
+                    .detail
+                    .as_ref()
+                    .is_some_and(|d| d.project.id == detail.project.id) =>
+            {
+                groups.issues = issues;
+                groups.arrange(teams);
+            }
+            _ => self.issues = Some(StateGroups::new(issues, teams)),
+        }
+        self.detail = Some(detail);
+    }
+
+    pub fn close(&mut self) {
+        self.detail = None;
+        self.issues = None;
+        self.pending = None;
+    }
+
+    pub fn selected_issue(&self) -> Option<&Issue> {
+        self.issues.as_ref()?.selected_issue()
+    }
+
+    /// Updates an issue of the open project in place, e.g. after a state change.
+    pub fn replace_issue(&mut self, issue: &Issue, teams: &[Team]) {
+        let Some(groups) = &mut self.issues else {
+            return;
+        };
+        if groups.issues.iter().any(|i| i.id == issue.id) {
+            groups.upsert(issue, true, teams);
+        }
+    }
+
+    pub fn arrange_rows(&mut self, teams: &[Team]) {
+        if let Some(groups) = &mut self.issues {
+            groups.arrange(teams);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use chrono::{DateTime, Local, Utc};
+
+/// "just now", "5m ago", "3h ago", "2d ago", "4mo ago" or "1y ago".
+pub fn relative(time: DateTime<Utc>) -> String {
+    relative_to(time, Utc::now())
+}
+
+fn relative_to(time: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let elapsed = now.signed_duration_since(time);
+
+    if elapsed.num_minutes() < 1 {
+        "just now".to_string()
+    } else if elapsed.num_hours() < 1 {
+        format!("{}m ago", elapsed.num_minutes())
+    } else if elapsed.num_days() < 1 {
+        format!("{}h ago", elapsed.num_hours())
+    } else if elapsed.num_days() < 30 {
+        format!("{}d ago", elapsed.num_days())
+    } else if elapsed.num_days() / 30 < 12 {
+        format!("{}mo ago", elapsed.num_days() / 30)
+    } else {
+        // 360 to 364 days is twelve 30-day months, so already a year.
+        format!("{}y ago", (elapsed.num_days() / 365).max(1))
+    }
+}
+
+/// The local date, e.g. "Oct 6".
+pub fn day(time: DateTime<Utc>) -> String {
+    time.with_timezone(&Local).format("%b %-d").to_string()
+}
+
+/// The time in the local timezone, to the minute.
+pub fn local(time: DateTime<Utc>) -> String {
+    time.with_timezone(&Local)
+        .format("%Y-%m-%d %H:%M")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+
+    fn ago(elapsed: TimeDelta) -> String {
+        let now = Utc::now();
+        relative_to(now - elapsed, now)
+    }
+
+    #[test]
+    fn relative_to_picks_the_largest_whole_unit() {
+        assert_eq!(ago(TimeDelta::seconds(59)), "just now");
+        assert_eq!(ago(TimeDelta::minutes(1)), "1m ago");
+        assert_eq!(ago(TimeDelta::minutes(59)), "59m ago");
+        assert_eq!(ago(TimeDelta::hours(1)), "1h ago");
+        assert_eq!(ago(TimeDelta::hours(23)), "23h ago");
+        assert_eq!(ago(TimeDelta::days(1)), "1d ago");
+        assert_eq!(ago(TimeDelta::days(29)), "29d ago");
+        assert_eq!(ago(TimeDelta::days(30)), "1mo ago");
+        assert_eq!(ago(TimeDelta::days(359)), "11mo ago");
+        assert_eq!(ago(TimeDelta::days(360)), "1y ago");
+        assert_eq!(ago(TimeDelta::days(364)), "1y ago");
+        assert_eq!(ago(TimeDelta::days(365)), "1y ago");
+        assert_eq!(ago(TimeDelta::days(800)), "2y ago");
+    }
+
+    #[test]
+    fn relative_to_treats_the_future_as_now() {
+        assert_eq!(ago(TimeDelta::hours(-3)), "just now");
+    }
+}
@@ -0,0 +1,1348 @@
+use std::rc::Rc;
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+use crate::api::types::{
+    Cycle, CycleStatus, Issue, IssueCycle, IssueDetail, Notification, Priority, Project,
+    ProjectDetail, ProjectHealth,
+};
+use crate::app::{App, Recovery, Tab, View};
+use crate::board::{Board, BoardBy};
+use crate::column::Column;
+use crate::filter::TextMatch;
+use crate::form::{CommentEditor, CommentTarget, FIELDS, Field, IssueForm};
+use crate::list::{GroupBy, Row, StateGroups};
+use crate::picker::Picker;
+use crate::time;
+
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Splits the screen into the header, the main area and the footer.
+fn layout(area: Rect) -> Rc<[Rect]> {
+    Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(area)
+}
+
+/// Where the detail view's text goes on a screen of `area`, inside its border.
+pub fn detail_viewport(area: Rect) -> Rect {
+    Block::default()
+        .borders(Borders::ALL)
+        .inner(layout(area)[1])
+}
+
+pub fn draw(f: &mut Frame, app: &App) {
+    let chunks = layout(f.area());
+
+    let mut title = match &app.team {
+        Some(key) => format!("Linear TUI · {}", key),
+        None => "Linear TUI".to_string(),
+    };
+    if app.tab == Tab::Issues && app.is_scoped() {
+        title = format!("{} · {}", title, app.scope.label());
+    }
+    let mut header_text = if app.tab != Tab::Issues {
+        title
+    } else if app.search_mode {
+        format!(
+            "{} - Search ({}): {}_",
+            title,
+            app.search_scope.label(),
+            app.search_query
+        )
+    } else if !app.search_query.is_empty() {
+        format!(
+            "{} - Filter ({}): {}",
+            title,
+            app.search_scope.label(),
+            app.search_query
+        )
+    } else {
+        title
+    };
+    if let Some(view) = &app.current_view {
+        header_text = format!("{} [{}]", header_text, view);
+    }
+    if app.is_busy() {
+        header_text = format!("{} {}", header_text, spinner(app));
+    }
+
+    let mut header_spans = vec![Span::styled(
+        header_text,
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    )];
+    for (i, tab) in Tab::ALL.into_iter().enumerate() {
+        let style = if tab == app.tab {
+            Style::default().fg(Color::Black).bg(Color::Cyan)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let label = match tab {
+            Tab::Inbox if app.inbox.unread() > 0 => {
+                format!("{} ({})", tab.label(), app.inbox.unread())
+            }
+            _ => tab.label().to_string(),
+        };
+        header_spans.push(Span::raw("  "));
+        header_spans.push(Span::styled(format!(" {} {} ", i + 1, label), style));
+    }
+    if let Some(error) = &app.filter_error {
+        header_spans.push(Span::styled(
+            format!("  ✗ {}", error),
+            Style::default().fg(Color::Red),
+        ));
+    }
+    if let Some(warning) = app.rate_limit_warning() {
+        header_spans.push(Span::styled(
+            format!("  ⚠ Rate limit: {}", warning),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+
+    let header =
+        Paragraph::new(Line::from(header_spans)).block(Block::default().borders(Borders::ALL));
+    f.render_widget(header, chunks[0]);
+
+    if let Some(error) = &app.error {
+        let title = match error.recovery {
+            Recovery::None => "Error",
+            Recovery::Retry => "Error - press r to retry",
+            Recovery::Reauthenticate => "Signed out - press L to log in again",
+        };
+        let error_msg = Paragraph::new(error.message.as_str())
+            .style(Style::default().fg(Color::Red))
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::ALL).title(title));
+        f.render_widget(error_msg, chunks[1]);
+    } else if let View::Detail = app.view {
+        match &app.detail {
+            Some(detail) => render_detail(f, chunks[1], app, detail),
+            None => {
+                let loading = Paragraph::new(format!("{} Loading issue...", spinner(app)))
+                    .block(Block::default().borders(Borders::ALL).title("Issue"));
+                f.render_widget(loading, chunks[1]);
+            }
+        }
+    } else if app.tab == Tab::Projects {
+        render_projects(f, chunks[1], app);
+    } else if app.tab == Tab::Cycles {
+        render_cycles(f, chunks[1], app);
+    } else if app.tab == Tab::Inbox {
+        render_inbox(f, chunks[1], app);
+    } else if app.loading && app.issues.is_empty() {
+        let loading = Paragraph::new(format!("{} Loading issues...", spinner(app)))
+            .block(Block::default().borders(Borders::ALL).title("Issues"));
+        f.render_widget(loading, chunks[1]);
+    } else if let Some(board) = &app.board {
+        render_board(f, chunks[1], app, board);
+    } else {
+        let items: Vec<ListItem> = app
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| {
+                let style = if index == app.selected_index {
+                    Style::default().bg(Color::DarkGray).fg(Color::White)
+                } else {
+                    Style::default()
+                };
+
+                let content = match row {
+                    Row::Group {
+                        label,
+                        color,
+                        count,
+                        collapsed,
+                        ..
+                    } => group_header(label, color.as_deref(), *count, *collapsed),
+                    Row::Issue(i) => issue_line(
+                        &app.issues[*i],
+                        app.text_matches.get(i),
+                        app.group_by != GroupBy::None,
+                        &app.columns,
+                    ),
+                };
+
+                ListItem::new(content).style(style)
+            })
+            .collect();
+
+        let more = if app.has_more_issues() { "+" } else { "" };
+        let mut title = if app.filtered_issues.len() < app.issues.len() {
+            format!(
+                "Issues ({}/{}{})",
+                app.filtered_issues.len(),
+                app.issues.len(),
+                more
+            )
+        } else {
+            format!("Issues ({}{})", app.issues.len(), more)
+        };
+        title.push_str(&format!(" · sort: {}", app.sort.label()));
+        if app.group_by != GroupBy::None {
+            title.push_str(&format!(" · group: {}", app.group_by.label()));
+        }
+        let title = if app.loading || app.loading_more {
+            format!("{} {}", title, spinner(app))
+        } else {
+            title
+        };
+
+        let main_list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .style(Style::default().fg(Color::White));
+        let mut list_state = ListState::default().with_selected(Some(app.selected_index));
+        f.render_stateful_widget(main_list, chunks[1], &mut list_state);
+    }
+
+    let footer_text = if let Some(prompt) = &app.view_prompt {
+        format!(
+            "Save view to {} as: {}_  [Enter] save | [Tab] where | [Esc] cancel",
+            prompt.target.label(),
+            prompt.name
+        )
+    } else if app.search_mode {
+        "[Esc] clear | [Enter] apply | [Tab] scope | text, field:value, -field:value; fields: state assignee creator priority label team project created updated due"
+            .to_string()
+    } else if app.pending_delete.is_some() {
+        "Delete this comment? [y]es | [n]o".to_string()
+    } else if let View::Detail = app.view {
+        "[Esc] back | [s]tate | [p]riority | c[y]cle | [c]omment | [Tab] next comment | [e]dit | [d]elete | [x] resolve"
+            .to_string()
+    } else if (app.tab == Tab::Projects && app.projects.is_open())
+        || (app.tab == Tab::Cycles && app.cycles.open.is_some())
+    {
+        "[Esc] back | [r]efresh | [Enter] open issue / fold group | c[y]cle | [↑/k] up | [↓/j] down | [1-4] tabs"
+            .to_string()
+    } else if app.tab == Tab::Inbox {
+        "[q]uit | [r]efresh | [Enter] open issue | [m]ark read | [M]ark all read | [a]rchive | [z] snooze | [↑/k] up | [↓/j] down | [1-4] tabs"
+            .to_string()
+    } else if app.tab == Tab::Cycles {
+        "[q]uit | [r]efresh | [Enter] open cycle | [t]eam | [↑/k] up | [↓/j] down | [1-4] tabs"
+            .to_string()
+    } else if app.tab == Tab::Projects {
+        "[q]uit | [r]efresh | [Enter] open project | [t]eam | [↑/k] up | [↓/j] down | [1-4] tabs"
+            .to_string()
+    } else if app.board.is_some() {
+        "[q]uit | [r]efresh | [/] search | [Enter] open | [h/l] column | [j/k] issue | [H/L] move | [G] columns by | [b] list | [n]ew | [s]tate | [p]riority | c[y]cle | [o]rder | [m]y issues | [t]eam | [v]iews | [1-4] tabs"
+            .to_string()
+    } else {
+        "[q]uit | [r]efresh | [/] search | [Enter] open | [n]ew | [s]tate | [p]riority | c[y]cle | [C]olumns | [o]rder | [G]roup | [b]oard | [m]y issues | [t]eam | [v]iews | [V] save view | [↑/k] up | [↓/j] down | [1-4] tabs"
+            .to_string()
+    };
+
+    let footer = Paragraph::new(footer_text)
+        .style(Style::default().fg(Color::DarkGray))
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(footer, chunks[2]);
+
+    if let Some(form) = &app.issue_form {
+        render_issue_form(f, app, form);
+    }
+    if let Some(picker) = &app.picker {
+        render_picker(f, picker);
+    }
+    if let Some(editor) = &app.comment_editor {
+        render_comment_editor(f, app, editor);
+    }
+}
+
+fn spinner(app: &App) -> &'static str {
+    SPINNER[app.tick % SPINNER.len()]
+}
+
+fn priority_color(priority: Priority) -> Color {
+    match priority {
+        Priority::None => Color::DarkGray,
+        Priority::Urgent => Color::Red,
+        Priority::High => Color::LightRed,
+        Priority::Medium => Color::Yellow,
+        Priority::Low => Color::Blue,
+    }
+}
+
+/// Truncates or pads `text` to exactly `width` characters.
+fn fit(text: &str, width: usize) -> String {
+    format!("{:<width$.width$}", text, width = width)
+}
+
+fn column_cell(column: Column, issue: &Issue) -> Vec<Span<'static>> {
+    let width = column.width();
+    let text = match column {
+        Column::Team => issue.team.key.clone(),
+        Column::Project => issue
+            .project
+            .as_ref()
+            .map(|p| p.name.clone())
+            .unwrap_or_default(),
+        Column::Cycle => issue
+            .cycle
+            .as_ref()
+            .map(IssueCycle::label)
+            .unwrap_or_default(),
+        Column::Estimate => issue.estimate.map(|e| e.to_string()).unwrap_or_default(),
+        Column::DueDate => issue
+            .due_date
+            .map(|date| date.to_string())
+            .unwrap_or_default(),
+        Column::Created => time::relative(issue.created_at),
+        Column::Updated => time::relative(issue.updated_at),
+        Column::Labels => {
+            // Each label in its own colour, as many as fit.
+            let mut spans = Vec::new();
+            let mut used = 0;
+            for label in &issue.labels {
+                let separator = if spans.is_empty() { "" } else { " " };
+                let remaining = width.saturating_sub(used + separator.len());
+                if remaining == 0 {
+                    break;
+                }
+                let name: String = label.name.chars().take(remaining).collect();
+                used += separator.len() + name.chars().count();
+                spans.push(Span::raw(separator));
+                spans.push(Span::styled(
+                    name,
+                    Style::default().fg(hex_color(&label.color)),
+                ));
+            }
+            spans.push(Span::raw(" ".repeat(width - used)));
+            return spans;
+        }
+    };
+
+    vec![Span::raw(fit(&text, width))]
+}
+
+fn group_header(label: &str, color: Option<&str>, count: usize, collapsed: bool) -> Line<'static> {
+    let marker = if collapsed { "▸" } else { "▾" };
+    let color = color.map_or(Color::White, hex_color);
+
+    Line::from(vec![
+        Span::raw(format!("{} ", marker)),
+        Span::styled(
+            label.to_string(),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(format!(" ({})", count), Style::default().fg(Color::Gray)),
+    ])
+}
+
+fn issue_line(
+    issue: &Issue,
+    text_match: Option<&TextMatch>,
+    grouped: bool,
+    columns: &[Column],
+) -> Line<'static> {
+    let assignee = issue
+        .assignee
+        .as_ref()
+        .map(|u| u.name.chars().take(10).collect::<String>())
+        .unwrap_or_else(|| "Unassigned".to_string());
+    // Indent issues under their group header.
+    let indent = if grouped { "  " } else { "" };
+
+    let mut spans = vec![
+        Span::raw(indent),
+        Span::styled(
+            issue.priority.icon(),
+            Style::default().fg(priority_color(issue.priority)),
+        ),
+        Span::raw(" "),
+    ];
+    spans.extend(highlight(
+        &issue.identifier,
+        text_match.map_or(&[], |m| &m.identifier),
+    ));
+    spans.extend([
+        Span::raw(" │ "),
+        Span::styled(
+            format!("{:>12}", issue.state.name),
+            Style::default().fg(hex_color(&issue.state.color)),
+        ),
+        Span::raw(format!(" │ {:<10} │ ", assignee)),
+    ]);
+    for &column in columns {
+        spans.extend(column_cell(column, issue));
+        spans.push(Span::raw(" │ "));
+    }
+    spans.extend(highlight(
+        &issue.title,
+        text_match.map_or(&[], |m| &m.title),
+    ));
+    Line::from(spans)
+}
+
+/// Splits `text` into spans, emphasising the characters at `positions`.
+fn highlight(text: &str, positions: &[usize]) -> Vec<Span<'static>> {
+    if positions.is_empty() {
+        return vec![Span::raw(text.to_string())];
+    }
+    let matched = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+
+    let mut spans: Vec<Span> = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+    for (i, c) in text.chars().enumerate() {
+        let is_matched = positions.contains(&i);
+        if is_matched != run_matched && !run.is_empty() {
+            let run = std::mem::take(&mut run);
+            spans.push(if run_matched {
+                Span::styled(run, matched)
+            } else {
+                Span::raw(run)
+            });
+        }
+        run_matched = is_matched;
+        run.push(c);
+    }
+    spans.push(if run_matched {
+        Span::styled(run, matched)
+    } else {
+        Span::raw(run)
+    });
+    spans
+}
+
+/// Parses the `#rrggbb` colours Linear uses for states and labels.
+fn hex_color(hex: &str) -> Color {
+    let hex = hex.trim_start_matches('#');
+    // Checked up front so that the slices below fall on char boundaries.
+    if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Color::White;
+    }
+
+    match (
+        u8::from_str_radix(&hex[0..2], 16),
+        u8::from_str_radix(&hex[2..4], 16),
+        u8::from_str_radix(&hex[4..6], 16),
+    ) {
+        (Ok(r), Ok(g), Ok(b)) => Color::Rgb(r, g, b),
+        _ => Color::White,
+    }
+}
+
+/// Builds the detail view's lines, returning alongside them the line index of
+/// each comment's header so the view can scroll a selected comment into sight.
+fn detail_lines(
+    detail: &IssueDetail,
+    selected_comment: Option<usize>,
+) -> (Vec<Line<'static>>, Vec<usize>) {
+    let issue = &detail.issue;
+    let label = Style::default().fg(Color::DarkGray);
+    let heading = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+
+    let field = |name: &str, value: Vec<Span<'static>>| {
+        let mut spans = vec![Span::styled(format!("{:<12}", format!("{}:", name)), label)];
+        spans.extend(value);
+        Line::from(spans)
+    };
+    let text = |value: String| vec![Span::raw(value)];
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            issue.title.clone(),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::default(),
+        field(
+            "State",
+            vec![Span::styled(
+                issue.state.name.clone(),
+                Style::default().fg(hex_color(&issue.state.color)),
+            )],
+        ),
+        field(
+            "Priority",
+            vec![Span::styled(
+                format!("{} {}", issue.priority.icon(), issue.priority.label()),
+                Style::default().fg(priority_color(issue.priority)),
+            )],
+        ),
+        field(
+            "Assignee",
+            text(
+                issue
+                    .assignee
+                    .as_ref()
+                    .map(|u| u.name.clone())
+                    .unwrap_or_else(|| "Unassigned".to_string()),
+            ),
+        ),
+        field(
+            "Team",
+            text(format!("{} ({})", issue.team.name, issue.team.key)),
+        ),
+    ];
+
+    if let Some(project) = &issue.project {
+        lines.push(field("Project", text(project.name.clone())));
+    }
+    if let Some(cycle) = &issue.cycle {
+        lines.push(field("Cycle", text(cycle.label())));
+    }
+    if !issue.labels.is_empty() {
+        let mut spans = Vec::new();
+        for (i, l) in issue.labels.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw(", "));
+            }
+            spans.push(Span::styled(
+                l.name.clone(),
+                Style::default().fg(hex_color(&l.color)),
+            ));
+        }
+        lines.push(field("Labels", spans));
+    }
+    if let Some(estimate) = issue.estimate {
+        lines.push(field("Estimate", text(estimate.to_string())));
+    }
+    if let Some(due_date) = &issue.due_date {
+        lines.push(field("Due", text(due_date.to_string())));
+    }
+    if let Some(parent) = &issue.parent {
+        lines.push(field("Parent", text(parent.identifier.clone())));
+    }
+    if !issue.children.is_empty() {
+        let children: Vec<&str> = issue
+            .children
+            .iter()
+            .map(|child| child.identifier.as_str())
+            .collect();
+        lines.push(field("Sub-issues", text(children.join(", "))));
+    }
+    if let Some(creator) = &issue.creator {
+        lines.push(field("Creator", text(creator.name.clone())));
+    }
+    lines.extend([
+        field("Created", text(time::local(issue.created_at))),
+        field("Updated", text(time::local(issue.updated_at))),
+        field("Branch", text(issue.branch_name.clone())),
+        field("URL", text(issue.url.clone())),
+        Line::default(),
+        Line::from(Span::styled("Description", heading)),
+    ]);
+
+    match issue.description.as_deref() {
+        Some(description) if !description.trim().is_empty() => {
+            lines.extend(description.lines().map(|l| Line::from(l.to_string())));
+        }
+        _ => lines.push(Line::from(Span::styled("No description", label))),
+    }
+
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        format!("Comments ({})", detail.comments.len()),
+        heading,
+    )));
+
+    let mut offsets = Vec::with_capacity(detail.comments.len());
+    for (i, comment) in detail.comments.iter().enumerate() {
+        let author = comment
+            .user
+            .as_ref()
+            .map(|u| u.name.as_str())
+            .unwrap_or("Unknown");
+        let indent = if comment.parent_id.is_some() {
+            "    "
+        } else {
+            ""
+        };
+
+        let mut header = vec![
+            Span::raw(indent),
+            Span::styled(author.to_string(), Style::default().fg(Color::Yellow)),
+            Span::styled(format!(" · {}", time::local(comment.created_at)), label),
+        ];
+        if comment.edited_at.is_some() {
+            header.push(Span::styled(" · edited", label));
+        }
+        if comment.resolved_at.is_some() {
+            header.push(Span::styled(
+                " · ✓ resolved",
+                Style::default().fg(Color::Green),
+            ));
+        }
+        let mut header = Line::from(header);
+        if selected_comment == Some(i) {
+            header = header.style(Style::default().bg(Color::DarkGray));
+        }
+
+        lines.push(Line::default());
+        offsets.push(lines.len());
+        lines.push(header);
+        lines.extend(
+            comment
+                .body
+                .lines()
+                .map(|l| Line::from(format!("{}  {}", indent, l))),
+        );
+    }
+
+    (lines, offsets)
+}
+
+/// Row of each comment header in the detail view, with lines wrapped to `width`.
+pub fn comment_line_offsets(detail: &IssueDetail, width: u16) -> Vec<usize> {
+    let (lines, offsets) = detail_lines(detail, None);
+    let rows: Vec<usize> = lines
+        .into_iter()
+        .map(|line| {
+            Paragraph::new(line)
+                .wrap(Wrap { trim: false })
+                .line_count(width)
+        })
+        .collect();
+    offsets
+        .into_iter()
+        .map(|offset| rows[..offset].iter().sum())
+        .collect()
+}
+
+/// Rows the detail view's text takes with lines wrapped to `width`.
+pub fn detail_height(detail: &IssueDetail, width: u16) -> usize {
+    Paragraph::new(detail_lines(detail, None).0)
+        .wrap(Wrap { trim: false })
+        .line_count(width)
+}
+
+fn render_detail(f: &mut Frame, area: Rect, app: &App, detail: &IssueDetail) {
+    let (lines, _) = detail_lines(detail, app.selected_comment);
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(detail.issue.identifier.as_str()),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((app.detail_scroll, 0));
+    f.render_widget(paragraph, area);
+}
+
+/// Narrowest a board column gets; beyond that the board scrolls sideways.
+const BOARD_COLUMN_WIDTH: u16 = 28;
+
+fn render_board(f: &mut Frame, area: Rect, app: &App, board: &Board) {
+    if board.columns.is_empty() {
+        let empty = Paragraph::new("No issues").block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Board · by {}", board.by.label())),
+        );
+        f.render_widget(empty, area);
+        return;
+    }
+
+    // As many columns as fit, scrolled to keep the focused one in view.
+    let visible = usize::from(area.width / BOARD_COLUMN_WIDTH).clamp(1, board.columns.len());
+    let first = (board.focus + 1).saturating_sub(visible);
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, visible as u32); visible])
+        .split(area);
+
+    for (pane, (index, column)) in panes
+        .iter()
+        .zip(board.columns.iter().enumerate().skip(first))
+    {
+        let focused = index == board.focus;
+        let width = usize::from(pane.width.saturating_sub(2));
+        let items: Vec<ListItem> = column
+            .issues
+            .iter()
+            .enumerate()
+            .map(|(row, &i)| {
+                let style = if focused && row == column.selected {
+                    Style::default().bg(Color::DarkGray).fg(Color::White)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(board_card(&app.issues[i], board.by, width)).style(style)
+            })
+            .collect();
+
+        let mut title = vec![Span::styled(
+            column.lane.label().to_string(),
+            Style::default()
+                .fg(column.lane.color().map_or(Color::White, hex_color))
+                .add_modifier(Modifier::BOLD),
+        )];
+        title.push(Span::styled(
+            format!(" ({})", column.issues.len()),
+            Style::default().fg(Color::Gray),
+        ));
+        if index == first && first > 0 {
+            title.insert(0, Span::raw("◂ "));
+        }
+        if index + 1 == first + visible && index + 1 < board.columns.len() {
+            title.push(Span::raw(" ▸"));
+        }
+        let border = if focused {
+            Style::default().fg(Color::Cyan)
+        } else {
+            Style::default()
+        };
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(border)
+                    .title(Line::from(title)),
+            )
+            .style(Style::default().fg(Color::White));
+        let mut state = ListState::default().with_selected(Some(column.selected));
+        f.render_stateful_widget(list, *pane, &mut state);
+    }
+}
+
+/// An issue on the board: priority, identifier and whichever of state and
+/// assignee the columns don't show, then the title.
+fn board_card(issue: &Issue, by: BoardBy, width: usize) -> Vec<Line<'static>> {
+    let detail = match by {
+        BoardBy::State => Span::raw(
+            issue
+                .assignee
+                .as_ref()
+                .map_or("Unassigned".to_string(), |u| u.name.clone()),
+        ),
+        BoardBy::Assignee => Span::styled(
+            issue.state.name.clone(),
+            Style::default().fg(hex_color(&issue.state.color)),
+        ),
+    };
+    vec![
+        Line::from(vec![
+            Span::styled(
+                issue.priority.icon(),
+                Style::default().fg(priority_color(issue.priority)),
+            ),
+            Span::raw(format!(" {} · ", issue.identifier)),
+            detail,
+        ]),
+        Line::from(issue.title.chars().take(width).collect::<String>()),
+    ]
+}
+
+fn render_projects(f: &mut Frame, area: Rect, app: &App) {
+    let tab = &app.projects;
+    if let (Some(detail), Some(issues)) = (&tab.detail, &tab.issues) {
+        render_project(f, area, app, detail, issues);
+        return;
+    }
+    let loading = match &tab.pending {
+        Some(_) => Some("Loading project..."),
+        None if tab.loading && tab.projects.is_empty() => Some("Loading projects..."),
+        None => None,
+    };
+    if let Some(message) = loading {
+        let loading = Paragraph::new(format!("{} {}", spinner(app), message))
+            .block(Block::default().borders(Borders::ALL).title("Projects"));
+        f.render_widget(loading, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = tab
+        .projects
+        .iter()
+        .enumerate()
+        .map(|(index, project)| {
+            let style = if index == tab.selected {
+                Style::default().bg(Color::DarkGray).fg(Color::White)
+            } else {
+                Style::default()
+            };
+            ListItem::new(project_line(project)).style(style)
+        })
+        .collect();
+
+    let mut title = format!("Projects ({})", tab.projects.len());
+    if tab.loading {
+        title = format!("{} {}", title, spinner(app));
+    }
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .style(Style::default().fg(Color::White));
+    let mut state = ListState::default().with_selected(Some(tab.selected));
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn project_line(project: &Project) -> Line<'static> {
+    let lead = project
+        .lead
+        .as_ref()
+        .map_or("No lead".to_string(), |lead| lead.name.clone());
+    let target = project
+        .target_date
+        .map_or(String::new(), |date| date.to_string());
+    let teams: Vec<&str> = project.teams.iter().map(|team| team.key.as_str()).collect();
+
+    Line::from(vec![
+        Span::raw(format!("{} │ ", fit(&project.name, 30))),
+        Span::styled(
+            fit(&project.state.name, 12),
+            Style::default().fg(hex_color(&project.state.color)),
+        ),
+        Span::raw(" │ "),
+        Span::styled(
+            progress_bar(project.progress, 10),
+            Style::default().fg(Color::Green),
+        ),
+        Span::raw(format!(" {:>3.0}% │ ", project.progress * 100.0)),
+        Span::raw(format!("{} │ ", fit(&lead, 14))),
+        Span::raw(format!("{} │ ", fit(&target, 10))),
+        Span::styled(teams.join(" "), Style::default().fg(Color::Gray)),
+    ])
+}
+
+fn progress_bar(progress: f64, width: usize) -> String {
+    let filled = ((progress.clamp(0.0, 1.0) * width as f64).round() as usize).min(width);
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+/// The open project: its issues by status on the left, and its summary and
+/// latest updates on the right.
+fn render_project(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    detail: &ProjectDetail,
+    issues: &StateGroups,
+) {
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(area);
+
+    let mut title = format!("{} ({})", detail.project.name, issues.issues.len());
+    if app.projects.pending.is_some() {
+        title = format!("{} {}", title, spinner(app));
+    }
+    render_state_groups(f, panes[0], issues, title);
+
+    let paragraph = Paragraph::new(project_lines(detail))
+        .block(Block::default().borders(Borders::ALL).title("Project"))
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, panes[1]);
+}
+
+fn render_cycles(f: &mut Frame, area: Rect, app: &App) {
+    let tab = &app.cycles;
+    if let Some(cycle) = tab.open_cycle() {
+        render_cycle(f, area, app, cycle);
+        return;
+    }
+    if tab.loading && tab.cycles.is_empty() {
+        let loading = Paragraph::new(format!("{} Loading cycles...", spinner(app)))
+            .block(Block::default().borders(Borders::ALL).title("Cycles"));
+        f.render_widget(loading, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = tab
+        .cycles
+        .iter()
+        .enumerate()
+        .map(|(index, cycle)| {
+            let style = if index == tab.selected {
+                Style::default().bg(Color::DarkGray).fg(Color::White)
+            } else {
+                Style::default()
+            };
+            ListItem::new(cycle_line(cycle)).style(style)
+        })
+        .collect();
+
+    let mut title = format!("Cycles ({})", tab.cycles.len());
+    if tab.loading {
+        title = format!("{} {}", title, spinner(app));
+    }
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .style(Style::default().fg(Color::White));
+    let mut state = ListState::default().with_selected(Some(tab.selected));
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn cycle_status_color(status: CycleStatus) -> Color {
+    match status {
+        CycleStatus::Active => Color::Green,
+        CycleStatus::Upcoming => Color::Blue,
+        CycleStatus::Past => Color::DarkGray,
+    }
+}
+
+fn cycle_dates(cycle: &Cycle) -> String {
+    format!(
+        "{} – {}",
+        time::day(cycle.starts_at),
+        time::day(cycle.ends_at)
+    )
+}
+
+fn cycle_line(cycle: &Cycle) -> Line<'static> {
+    Line::from(vec![
+        Span::raw(format!("{} │ ", fit(&cycle.team.key, 6))),
+        Span::styled(
+            fit(cycle.status.label(), 8),
+            Style::default().fg(cycle_status_color(cycle.status)),
+        ),
+        Span::raw(format!(
+            " │ {} │ {} │ ",
+            fit(&cycle.label(), 20),
+            fit(&cycle_dates(cycle), 15)
+        )),
+        Span::styled(
+            progress_bar(cycle.progress, 10),
+            Style::default().fg(Color::Green),
+        ),
+        Span::raw(format!(
+            " {:>3.0}% │ {:>3}/{:<3} issues │ {}/{} points",
+            cycle.progress * 100.0,
+            cycle.completed_issue_count,
+            cycle.issue_count,
+            cycle.completed_scope,
+            cycle.scope
+        )),
+    ])
+}
+
+/// The open cycle: its issues by status on the left, its numbers on the right.
+fn render_cycle(f: &mut Frame, area: Rect, app: &App, cycle: &Cycle) {
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+        .split(area);
+
+    match &app.cycles.issues {
+        Some(issues) => {
+            let mut title = format!("{} ({})", cycle.label(), issues.issues.len());
+            if app.cycles.loading_issues {
+                title = format!("{} {}", title, spinner(app));
+            }
+            render_state_groups(f, panes[0], issues, title);
+        }
+        None => {
+            let loading = Paragraph::new(format!("{} Loading issues...", spinner(app)))
+                .block(Block::default().borders(Borders::ALL).title(cycle.label()));
+            f.render_widget(loading, panes[0]);
+        }
+    }
+
+    let label = Style::default().fg(Color::DarkGray);
+    let field = |name: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<10}", name), label),
+            Span::raw(value),
+        ])
+    };
+    let lines = vec![
+        field("Team", format!("{} ({})", cycle.team.name, cycle.team.key)),
+        Line::from(vec![
+            Span::styled(format!("{:<10}", "Status"), label),
+            Span::styled(
+                cycle.status.label(),
+                Style::default().fg(cycle_status_color(cycle.status)),
+            ),
+        ]),
+        field("Dates", cycle_dates(cycle)),
+        field(
+            "Progress",
+            format!(
+                "{} {:.0}%",
+                progress_bar(cycle.progress, 10),
+                cycle.progress * 100.0
+            ),
+        ),
+        field(
+            "Issues",
+            format!(
+                "{} of {} completed",
+                cycle.completed_issue_count, cycle.issue_count
+            ),
+        ),
+        field(
+            "Points",
+            format!("{} of {} completed", cycle.completed_scope, cycle.scope),
+        ),
+    ];
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title("Cycle"))
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, panes[1]);
+}
+
+fn render_inbox(f: &mut Frame, area: Rect, app: &App) {
+    let inbox = &app.inbox;
+    if inbox.notifications.is_empty() {
+        let text = if inbox.loading {
+            format!("{} Loading notifications...", spinner(app))
+        } else {
+            "No unread notifications".to_string()
+        };
+        let empty =
+            Paragraph::new(text).block(Block::default().borders(Borders::ALL).title("Inbox"));
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = inbox
+        .notifications
+        .iter()
+        .enumerate()
+        .map(|(index, notification)| {
+            let style = if index == inbox.selected {
+                Style::default().bg(Color::DarkGray).fg(Color::White)
+            } else if notification.read_at.is_some() {
+                Style::default().fg(Color::DarkGray)
+            } else {
+                Style::default()
+            };
+            ListItem::new(notification_line(notification)).style(style)
+        })
+        .collect();
+
+    let mut title = format!("Inbox ({} unread)", inbox.unread());
+    if inbox.loading {
+        title = format!("{} {}", title, spinner(app));
+    }
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+    let mut state = ListState::default().with_selected(Some(inbox.selected));
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn notification_line(notification: &Notification) -> Line<'static> {
+    let unread = if notification.read_at.is_none() {
+        Span::styled("● ", Style::default().fg(Color::Blue))
+    } else {
+        Span::raw("  ")
+    };
+    let actor = notification
+        .actor
+        .as_ref()
+        .map_or("Linear", |user| user.name.as_str());
+    let issue = notification.issue.as_ref().map_or(String::new(), |issue| {
+        format!("{} {}", issue.identifier, issue.title)
+    });
+
+    Line::from(vec![
+        unread,
+        Span::raw(format!(
+            "{:>8} │ {} │ {} │ ",
+            time::relative(notification.created_at),
+            fit(actor, 14),
+            fit(&notification.summary(), 26)
+        )),
+        Span::raw(issue),
+    ])
+}
+
+fn render_state_groups(f: &mut Frame, area: Rect, groups: &StateGroups, title: String) {
+    let items: Vec<ListItem> = groups
+        .rows
+        .iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let style = if index == groups.selected {
+                Style::default().bg(Color::DarkGray).fg(Color::White)
+            } else {
+                Style::default()
+            };
+            let content = match row {
+                Row::Group {
+                    label,
+                    color,
+                    count,
+                    collapsed,
+                    ..
+                } => group_header(label, color.as_deref(), *count, *collapsed),
+                Row::Issue(i) => issue_line(&groups.issues[*i], None, true, &[]),
+            };
+            ListItem::new(content).style(style)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .style(Style::default().fg(Color::White));
+    let mut state = ListState::default().with_selected(Some(groups.selected));
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn project_lines(detail: &ProjectDetail) -> Vec<Line<'static>> {
+    let project = &detail.project;
+    let label = Style::default().fg(Color::DarkGray);
+    let heading = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+    let field = |name: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<10}", name), label),
+            Span::raw(value),
+        ])
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(format!("{:<10}", "State"), label),
+            Span::styled(
+                project.state.name.clone(),
+                Style::default().fg(hex_color(&project.state.color)),
+            ),
+        ]),
+        field(
+            "Progress",
+            format!(
+                "{} {:.0}%",
+                progress_bar(project.progress, 10),
+                project.progress * 100.0
+            ),
+        ),
+        field(
+            "Lead",
+            project
+                .lead
+                .as_ref()
+                .map_or("No lead".to_string(), |lead| lead.name.clone()),
+        ),
+        field(
+            "Target",
+            project
+                .target_date
+                .map_or("No target date".to_string(), |date| date.to_string()),
+        ),
+        field(
+            "Teams",
+            project
+                .teams
+                .iter()
+                .map(|team| format!("{} ({})", team.name, team.key))
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        field("URL", project.url.clone()),
+    ];
+    if !project.description.is_empty() {
+        lines.push(Line::from(""));
+        lines.extend(
+            project
+                .description
+                .lines()
+                .map(|line| Line::from(line.to_string())),
+        );
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("Latest updates", heading)));
+    if detail.updates.is_empty() {
+        lines.push(Line::from(Span::styled("No updates yet", label)));
+    }
+    for update in &detail.updates {
+        let (health, color) = match update.health {
+            Some(health @ ProjectHealth::OnTrack) => (health.label(), Color::Green),
+            Some(health @ ProjectHealth::AtRisk) => (health.label(), Color::Yellow),
+            Some(health @ ProjectHealth::OffTrack) => (health.label(), Color::Red),
+            None => ("Update", Color::Gray),
+        };
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(health, Style::default().fg(color)),
+            Span::styled(
+                format!(
+                    " · {} · {}",
+                    update.user.name,
+                    time::relative(update.created_at)
+                ),
+                label,
+            ),
+        ]));
+        lines.extend(update.body.lines().map(|line| Line::from(line.to_string())));
+    }
+
+    lines
+}
+
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+fn render_issue_form(f: &mut Frame, app: &App, form: &IssueForm) {
+    let area = centered_rect(70, 12, f.area());
+    let team = app.teams.get(form.team);
+
+    let mut lines: Vec<Line> = FIELDS
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let focused = i == form.focus;
+            let (name, value) = match field {
+                Field::Team => (
+                    "Team",
+                    match team {
+                        Some(team) => format!("‹ {} · {} ›", team.key, team.name),
+                        None if app.loading_teams => "Loading teams...".to_string(),
+                        None => "No teams".to_string(),
+                    },
+                ),
+                Field::Title => ("Title", text_value(&form.title, focused)),
+                Field::Description => ("Description", text_value(&form.description, focused)),
+                Field::Priority => ("Priority", format!("‹ {} ›", form.priority.label())),
+                Field::Assignee => (
+                    "Assignee",
+                    format!(
+                        "‹ {} ›",
+                        form.assignee
+                            .checked_sub(1)
+                            .and_then(|i| team?.members.get(i))
+                            .map_or("Unassigned", |user| user.name.as_str())
+                    ),
+                ),
+                Field::State => (
+                    "State",
+                    format!(
+                        "‹ {} ›",
+                        form.state
+                            .checked_sub(1)
+                            .and_then(|i| team?.states.get(i))
+                            .map_or("Team default", |state| state.name.as_str())
+                    ),
+                ),
+            };
+
+            let label_style = if focused {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+
+            Line::from(vec![
+                Span::styled(format!("{:<12}", name), label_style),
+                Span::raw(value),
+            ])
+        })
+        .collect();
+
+    lines.push(Line::default());
+    if form.submitting {
+        lines.push(Line::from(format!("{} Creating issue...", spinner(app))));
+    } else if let Some(error) = &form.error {
+        lines.push(Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(Color::Red),
+        )));
+    } else {
+        lines.push(Line::default());
+    }
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        "[Tab] next field | [←/→] change | [Enter] create | [Esc] cancel",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let popup = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title("New Issue"))
+        .wrap(Wrap { trim: false });
+    f.render_widget(Clear, area);
+    f.render_widget(popup, area);
+}
+
+fn text_value(text: &str, focused: bool) -> String {
+    if focused {
+        format!("{}_", text)
+    } else {
+        text.to_string()
+    }
+}
+
+fn render_picker(f: &mut Frame, picker: &Picker) {
+    let height = (picker.items.len() as u16 + 2).min(20);
+    let area = centered_rect(40, height, f.area());
+
+    let items: Vec<ListItem> = picker
+        .items
+        .iter()
+        .map(|item| {
+            let mut spans = Vec::new();
+            if let Some(color) = &item.color {
+                spans.push(Span::styled("● ", Style::default().fg(hex_color(color))));
+            }
+            spans.push(Span::raw(item.label.clone()));
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(picker.title.as_str()),
+        )
+        .highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White));
+    let mut state = ListState::default().with_selected(Some(picker.selected));
+
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn render_comment_editor(f: &mut Frame, app: &App, editor: &CommentEditor) {
+    let area = centered_rect(70, 10, f.area());
+    let title = match editor.target {
+        CommentTarget::New { .. } => "New Comment",
+        CommentTarget::Edit { .. } => "Edit Comment",
+    };
+
+    let status = if editor.submitting {
+        Line::from(format!("{} Saving comment...", spinner(app)))
+    } else if let Some(error) = &editor.error {
+        Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red)))
+    } else {
+        Line::from(Span::styled(
+            "[Ctrl+S] save | [Enter] new line | [Esc] cancel",
+            Style::default().fg(Color::DarkGray),
+        ))
+    };
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(Block::default().borders(Borders::ALL).inner(area));
+
+    f.render_widget(Clear, area);
+    f.render_widget(Block::default().borders(Borders::ALL).title(title), area);
+    // Keep the end of the text, where the cursor is, in view.
+    let text = Paragraph::new(format!("{}_", editor.text)).wrap(Wrap { trim: false });
+    let rows = text.line_count(layout[0].width);
+    let scroll = rows.saturating_sub(layout[0].height.into());
+    f.render_widget(
+        text.scroll((scroll.try_into().unwrap_or(u16::MAX), 0)),
+        layout[0],
+    );
+    f.render_widget(Paragraph::new(status), layout[1]);
+}
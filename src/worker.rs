@@ -0,0 +1,113 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use crate::api::error::Result;
+use crate::api::rate_limit::RateLimit;
+
+use crate::api::client::{IssuePages, LinearClient};
+use crate::api::types::{
+    Comment, CustomView, Cycle, Issue, IssueDetail, Notification, Project, ProjectDetail, Team,
+    User,
+};
+
+/// Results of background requests, delivered to the UI loop over a channel.
+pub enum Message {
+    IssuePage {
+        pages: IssuePages,
+        result: Option<Result<Vec<Issue>>>,
+        append: bool,
+    },
+    IssueDetail {
+        id: String,
+        result: Box<Result<IssueDetail>>,
+    },
+    Teams(Result<Vec<Team>>),
+    IssueCreated(Box<Result<Issue>>),
+    IssueUpdated(Box<Result<Issue>>),
+    /// An issue moved on the board, which already shows it in its new column.
+    IssueMoved {
+        /// The issue as it was, to put back if the update failed.
+        previous: Box<Issue>,
+        result: Box<Result<Issue>>,
+    },
+    Viewer(Result<User>),
+    Projects {
+        /// The team the projects were limited to.
+        team: Option<String>,
+        result: Result<Vec<Project>>,
+    },
+    ProjectDetail {
+        id: String,
+        result: Box<Result<ProjectDetail>>,
+    },
+    Cycles {
+        /// The team the cycles were limited to.
+        team: Option<String>,
+        result: Result<Vec<Cycle>>,
+    },
+    CycleIssues {
+        id: String,
+        result: Result<Vec<Issue>>,
+    },
+    /// Cycles to offer in the picker for moving an issue.
+    CycleChoices {
+        issue_id: String,
+        result: Result<Vec<Cycle>>,
+    },
+    Notifications(Result<Vec<Notification>>),
+    NotificationRead {
+        id: String,
+        result: Result<()>,
+    },
+    /// Every notification with one of `ids` was marked read.
+    NotificationsRead {
+        ids: Vec<String>,
+        result: Result<()>,
+    },
+    /// A notification was archived or snoozed, so it leaves the inbox.
+    NotificationDismissed {
+        id: String,
+        result: Result<()>,
+    },
+    CustomViews(Result<Vec<CustomView>>),
+    CustomViewCreated(Box<Result<CustomView>>),
+    CommentSaved {
+        issue_id: String,
+        result: Box<Result<Comment>>,
+    },
+    CommentDeleted {
+        issue_id: String,
+        comment_id: String,
+        result: Result<()>,
+    },
+}
+
+/// Runs API calls off the UI thread so rendering and input never wait on the network.
+pub struct Worker {
+    client: LinearClient,
+    tx: Sender<Message>,
+}
+
+impl Worker {
+    pub fn new(client: LinearClient) -> (Self, Receiver<Message>) {
+        let (tx, rx) = mpsc::channel();
+        (Self { client, tx }, rx)
+    }
+
+    pub fn rate_limit(&self) -> RateLimit {
+        self.client.rate_limit()
+    }
+
+    pub fn spawn<F>(&self, job: F)
+    where
+        F: FnOnce(&LinearClient) -> Message + Send + 'static,
+    {
+        let client = self.client.clone();
+        let tx = self.tx.clone();
+
+        thread::spawn(move || {
+            // The receiver only goes away once the UI has exited.
+            let _ = tx.send(job(&client));
+        });
+    }
+}